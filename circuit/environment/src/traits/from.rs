@@ -36,6 +36,15 @@ pub trait FromBits {
         Self: Sized;
 }
 
+/// Unary operator for instantiating from bytes.
+pub trait FromBytes {
+    type Byte;
+
+    fn from_bytes_le(bytes_le: &[Self::Byte]) -> Self
+    where
+        Self: Sized;
+}
+
 /// Unary operator for converting from a base field element.
 pub trait FromField {
     type Field: FieldTrait;
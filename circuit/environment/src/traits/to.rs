@@ -48,6 +48,14 @@ pub trait ToUpperBits {
     fn to_upper_bits_be(&self, k: usize) -> Vec<Self::Boolean>;
 }
 
+/// Unary operator for converting to bytes.
+pub trait ToBytes {
+    type Byte;
+
+    /// Returns the little-endian bytes of the circuit.
+    fn to_bytes_le(&self) -> Vec<Self::Byte>;
+}
+
 /// Unary operator for converting to a base field.
 pub trait ToField {
     type Field: FieldTrait;
@@ -67,65 +67,7 @@ impl<E: Environment, I0: IntegerType, I1: IntegerType> Cast<Integer<E, I1>> for
     /// Casts an `Integer` to another `Integer`, if the conversion is lossless.
     #[inline]
     fn cast(&self) -> Integer<E, I1> {
-        let mut bits_le = self.to_bits_le();
-        match (I0::is_signed(), I1::is_signed()) {
-            // If the two types are both unsigned, instantiate the new integer from the bits.
-            (false, false) => Integer::<E, I1>::from_bits_le(&bits_le),
-            // If the source type is unsigned and the destination type is signed, perform the required checks.
-            (false, true) => match I0::BITS < I1::BITS {
-                // If the source type is smaller than the destination type, instantiate the new integer from the bits.
-                true => Integer::<E, I1>::from_bits_le(&bits_le),
-                // If the source type is the same size or larger than the destination type, check that the most significant bits are zero.
-                // Then instantiate the new integer from the lower bits.
-                false => {
-                    Boolean::assert_bits_are_zero(&bits_le[(I1::BITS.saturating_sub(1) as usize)..]);
-                    Integer::<E, I1>::from_bits_le(&bits_le[..(I1::BITS as usize)])
-                }
-            },
-            // If the source type is signed and the destination type is unsigned, perform the required checks.
-            (true, false) => match I0::BITS <= I1::BITS {
-                // If the source type is smaller than or equal to the destination type, check that the most significant bit is zero.
-                // Then instantiate the new integer from the lower bits.
-                true => {
-                    E::assert(!&bits_le[I0::BITS.saturating_sub(1) as usize]);
-                    Integer::<E, I1>::from_bits_le(&bits_le)
-                }
-                // If the source type is larger than the destination type, check that the upper bits are zero.
-                // Then instantiate the new integer from the lower bits.
-                false => {
-                    Boolean::assert_bits_are_zero(&bits_le[(I1::BITS as usize)..]);
-                    Integer::<E, I1>::from_bits_le(&bits_le[..(I1::BITS as usize)])
-                }
-            },
-            // If the two types are both signed, perform the required checks.
-            (true, true) => match I0::BITS <= I1::BITS {
-                // If the source type is smaller than or equal to the destination type, sign extend the source integer.
-                // Then instantiate the new integer from the bits.
-                true => {
-                    bits_le.resize(I1::BITS as usize, self.msb().clone());
-                    Integer::<E, I1>::from_bits_le(&bits_le)
-                }
-                // If the source type is larger than the destination type, check that the upper bits match the most significant bit.
-                // Then instantiate the new integer from the appropriate lower bits.
-                false => {
-                    // Get the most significant bit.
-                    let msb = match bits_le.pop() {
-                        Some(bit) => bit,
-                        None => E::halt("Failed to extract the MSB from the integer."),
-                    };
-                    // Check that the upper bits match the most significant bit.
-                    let upper_bits = bits_le.iter().skip(I1::BITS.saturating_sub(1) as usize);
-                    for bit in upper_bits {
-                        E::assert_eq(&msb, bit);
-                    }
-                    // Instantiate the new integer from the lower bits and the most significant bit.
-                    let mut lower_bits: Vec<_> =
-                        bits_le.into_iter().take(I1::BITS.saturating_sub(1) as usize).collect();
-                    lower_bits.push(msb);
-                    Integer::<E, I1>::from_bits_le(&lower_bits)
-                }
-            },
-        }
+        self.cast_checked()
     }
 }
 
@@ -26,6 +26,7 @@ use snarkvm_circuit_types::prelude::{
     Address,
     BitOr,
     Boolean,
+    CastChecked,
     Environment,
     Field,
     FromBits,
@@ -40,7 +41,6 @@ use snarkvm_circuit_types::prelude::{
     ToField,
     ToGroup,
     Zero,
-    MSB,
 };
 
 #[cfg(test)]
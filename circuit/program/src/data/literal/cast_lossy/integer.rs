@@ -63,17 +63,7 @@ impl<E: Environment, I: IntegerType> CastLossy<Group<E>> for Integer<E, I> {
 impl<E: Environment, I0: IntegerType, I1: IntegerType> CastLossy<Integer<E, I1>> for Integer<E, I0> {
     /// Casts an `Integer` to an `Integer` of a different type, with lossy truncation.
     fn cast_lossy(&self) -> Integer<E, I1> {
-        let mut bits_le = self.to_bits_le();
-        // If the source type is smaller than the destination type, then perform the appropriate extension.
-        let padding = match I0::is_signed() {
-            // If the source type is signed, then sign extend.
-            true => self.msb().clone(),
-            // Otherwise, zero extend.
-            false => Boolean::constant(false),
-        };
-        bits_le.resize(I1::BITS as usize, padding);
-        // Construct the integer from the bits.
-        Integer::from_bits_le(&bits_le)
+        self.cast_wrapped()
     }
 }
 
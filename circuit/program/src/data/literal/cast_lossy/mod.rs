@@ -26,6 +26,7 @@ use snarkvm_circuit_types::prelude::{
     integers::Integer,
     Address,
     Boolean,
+    CastWrapped,
     Environment,
     Field,
     FromBits,
@@ -42,7 +43,6 @@ use snarkvm_circuit_types::prelude::{
     ToField,
     ToGroup,
     Zero,
-    MSB,
 };
 
 #[cfg(test)]
@@ -49,6 +49,14 @@ impl<E: Environment> Boolean<E> {
         // Return the boolean.
         Boolean(var.into())
     }
+
+    /// Initializes a new boolean from a variable, **without** checking that the variable is zero or one.
+    ///
+    /// This exists to test gadgets that must detect malformed bits, and must not be used on untrusted witnesses.
+    #[doc(hidden)]
+    pub fn from_variable_unchecked(var: Variable<E::BaseField>) -> Self {
+        Boolean(var.into())
+    }
 }
 
 #[cfg(console)]
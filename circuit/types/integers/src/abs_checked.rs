@@ -114,8 +114,12 @@ mod tests {
         let name = format!("Abs: {mode} one");
         check_abs::<I>(&name, console::Integer::one(), mode);
 
+        // Check the console::Integer::MAX case.
+        let name = format!("Abs: {mode} max");
+        check_abs::<I>(&name, console::Integer::MAX, mode);
+
         // Check the console::Integer::MIN (checked) case.
-        let name = format!("Abs: {mode} one");
+        let name = format!("Abs: {mode} min");
         check_abs::<I>(&name, console::Integer::MIN, mode);
     }
 
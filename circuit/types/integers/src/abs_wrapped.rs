@@ -104,8 +104,12 @@ mod tests {
         let name = format!("Abs: {mode} one");
         check_abs::<I>(&name, console::Integer::one(), mode);
 
+        // Check the console::Integer::MAX case.
+        let name = format!("Abs: {mode} max");
+        check_abs::<I>(&name, console::Integer::MAX, mode);
+
         // Check the console::Integer::MIN (wrapped) case.
-        let name = format!("Abs: {mode} one");
+        let name = format!("Abs: {mode} min");
         check_abs::<I>(&name, console::Integer::MIN, mode);
     }
 
@@ -133,6 +137,16 @@ mod tests {
     test_integer_unary!(run_test, u64, equals);
     test_integer_unary!(run_test, u128, equals);
 
+    #[test]
+    fn test_i8_min_wraps_to_min() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = Integer::<Circuit, i8>::new(mode, console::Integer::MIN).abs_wrapped();
+            assert_eq!(i8::MIN, *candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
     test_integer_unary!(#[ignore], run_exhaustive_test, u8, equals, exhaustive);
     test_integer_unary!(#[ignore], run_exhaustive_test, i8, equals, exhaustive);
 }
@@ -14,6 +14,8 @@
 
 use super::*;
 
+use core::iter::Sum;
+
 impl<E: Environment, I: IntegerType> Add<Integer<E, I>> for Integer<E, I> {
     type Output = Self;
 
@@ -61,6 +63,24 @@ impl<E: Environment, I: IntegerType> AddAssign<&Integer<E, I>> for Integer<E, I>
     }
 }
 
+impl<E: Environment, I: IntegerType> Sum<Integer<E, I>> for Integer<E, I> {
+    /// Returns the checked sum of the integers, or `zero` if the iterator is empty.
+    /// Note that this halts (or is unsatisfiable) on overflow, in the same manner as `add_checked`.
+    #[inline]
+    fn sum<Iter: Iterator<Item = Integer<E, I>>>(iter: Iter) -> Self {
+        iter.fold(Integer::zero(), |a, b| a + b)
+    }
+}
+
+impl<'a, E: Environment, I: IntegerType> Sum<&'a Integer<E, I>> for Integer<E, I> {
+    /// Returns the checked sum of the integers, or `zero` if the iterator is empty.
+    /// Note that this halts (or is unsatisfiable) on overflow, in the same manner as `add_checked`.
+    #[inline]
+    fn sum<Iter: Iterator<Item = &'a Integer<E, I>>>(iter: Iter) -> Self {
+        iter.fold(Integer::zero(), |a, b| a + b)
+    }
+}
+
 impl<E: Environment, I: IntegerType> AddChecked<Self> for Integer<E, I> {
     type Output = Self;
 
@@ -110,6 +130,24 @@ impl<E: Environment, I: IntegerType> AddChecked<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the sum of `self` and `other`, or an error if the sum of two constants overflows.
+    ///
+    /// Unlike `add_checked`, this method does not halt on a constant overflow, allowing the caller to recover.
+    /// If either operand is not a constant, then the overflow check is enforced as a constraint,
+    /// and an overflow will result in an unsatisfied circuit.
+    ///
+    /// The error is an `anyhow::Error`, as with the other fallible methods of the circuit types (e.g. `from_str`).
+    /// A constant overflow is the only failure, so callers that reach the `Err` case need not inspect the error.
+    pub fn try_add_checked(&self, other: &Integer<E, I>) -> Result<Self> {
+        // Check for an overflow on the addition of two constants.
+        if self.is_constant() && other.is_constant() && self.eject_value().checked_add(&other.eject_value()).is_none() {
+            bail!("Integer overflow on addition of two constants")
+        }
+        Ok(self.add_checked(other))
+    }
+}
+
 impl<E: Environment, I: IntegerType> Metrics<dyn Add<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
     type Case = (Mode, Mode);
 
@@ -221,6 +259,109 @@ mod tests {
         }
     }
 
+    fn check_try_add<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::new(mode_b, second);
+        Circuit::scope(name, || {
+            let candidate = a.try_add_checked(&b);
+            match (first.checked_add(&second), mode_a.is_constant() && mode_b.is_constant()) {
+                (Some(expected), _) => {
+                    assert_eq!(expected, *candidate.unwrap().eject_value());
+                    assert_count!(Add(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                }
+                (None, true) => assert!(candidate.is_err()),
+                (None, false) => {
+                    assert!(candidate.is_ok());
+                    assert_count_fails!(Add(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                }
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_try_add_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("TryAdd: {mode_a} + {mode_b} {i}");
+            check_try_add::<I>(&name, first, second, mode_a, mode_b);
+        }
+
+        // Overflow
+        check_try_add::<I>("MAX + 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+
+        // Underflow
+        if I::is_signed() {
+            check_try_add::<I>("MIN + (-1)", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+        }
+    }
+
+    fn run_operator_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let forms: [BinaryOperator<I>; 6] = binary_operator_forms!(+, +=);
+        let check = |candidate: &Integer<Circuit, I>| {
+            assert_count!(Add(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Add(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        };
+
+        let mut samples: Vec<(console::Integer<_, I>, console::Integer<_, I>)> =
+            (0..ITERATIONS).map(|_| (Uniform::rand(&mut rng), Uniform::rand(&mut rng))).collect();
+
+        // Include operands that overflow and underflow, which the operator must reject.
+        samples.push((console::Integer::MAX, console::Integer::one()));
+        if I::is_signed() {
+            samples.push((console::Integer::MIN, -console::Integer::one()));
+        }
+
+        for (i, (first, second)) in samples.into_iter().enumerate() {
+            let a = Integer::<Circuit, I>::new(mode_a, first);
+            let b = Integer::<Circuit, I>::new(mode_b, second);
+            let expected = first.checked_add(&second).map(console::Integer::new);
+            let name = format!("AddOperator: {mode_a} + {mode_b} {i}");
+            check_binary_operator_forms(&name, &forms, &a, &b, expected, check);
+        }
+    }
+
+    fn run_sum_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for length in 0..8 {
+            // Sample operands small enough that their sum cannot overflow.
+            let integers: Vec<Integer<Circuit, I>> =
+                (0..length).map(|_| Integer::rand_bounded(mode, &mut rng, I::zero(), I::from(15).unwrap())).collect();
+            let expected = integers.iter().fold(I::zero(), |a, b| a + *b.eject_value());
+
+            Circuit::scope(format!("Sum: {mode} {length}"), || {
+                let candidate: Integer<Circuit, I> = integers.iter().sum();
+                assert_eq!(expected, *candidate.eject_value());
+                let candidate: Integer<Circuit, I> = integers.clone().into_iter().sum();
+                assert_eq!(expected, *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+
+        // Ensure the empty sum is a constant `zero`.
+        let candidate: Integer<Circuit, I> = core::iter::empty::<Integer<Circuit, I>>().sum();
+        assert_eq!((Mode::Constant, console::Integer::zero()), candidate.eject());
+
+        // Ensure an overflowing sum of constants halts.
+        let a = Integer::<Circuit, I>::constant(console::Integer::MAX);
+        let b = Integer::<Circuit, I>::constant(console::Integer::one() + console::Integer::one());
+        check_operation_halts(&a, &b, |a, b| [a, b].into_iter().sum::<Integer<Circuit, I>>());
+        Circuit::reset();
+    }
+
     fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
     where
         RangeInclusive<I>: Iterator<Item = I>,
@@ -248,6 +389,35 @@ mod tests {
     test_integer_binary!(run_test, u64, plus);
     test_integer_binary!(run_test, u128, plus);
 
+    test_integer_binary!(run_try_add_test, i8, try_plus);
+    test_integer_binary!(run_try_add_test, i16, try_plus);
+    test_integer_binary!(run_try_add_test, i32, try_plus);
+    test_integer_binary!(run_try_add_test, i64, try_plus);
+    test_integer_binary!(run_try_add_test, i128, try_plus);
+
+    test_integer_binary!(run_try_add_test, u8, try_plus);
+    test_integer_binary!(run_try_add_test, u16, try_plus);
+    test_integer_binary!(run_try_add_test, u32, try_plus);
+    test_integer_binary!(run_try_add_test, u64, try_plus);
+    test_integer_binary!(run_try_add_test, u128, try_plus);
+
+    test_integer_binary!(run_operator_test, i8, plus_operator);
+    test_integer_binary!(run_operator_test, i64, plus_operator);
+    test_integer_binary!(run_operator_test, u32, plus_operator);
+    test_integer_binary!(run_operator_test, u128, plus_operator);
+
+    test_integer_unary!(run_sum_test, i8, sum);
+    test_integer_unary!(run_sum_test, i16, sum);
+    test_integer_unary!(run_sum_test, i32, sum);
+    test_integer_unary!(run_sum_test, i64, sum);
+    test_integer_unary!(run_sum_test, i128, sum);
+
+    test_integer_unary!(run_sum_test, u8, sum);
+    test_integer_unary!(run_sum_test, u16, sum);
+    test_integer_unary!(run_sum_test, u32, sum);
+    test_integer_unary!(run_sum_test, u64, sum);
+    test_integer_unary!(run_sum_test, u128, sum);
+
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, plus, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, plus, exhaustive);
 }
@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the sum of `values`, or zero if `values` is empty.
+    ///
+    /// Rather than propagating a carry after every addition, the operands are accumulated in the base field,
+    /// which acts as a carry-save representation, as field additions are linear combinations and cost no constraints.
+    /// The accumulated sum is then decomposed into `I::BITS` bits once, which serves as the single carry-propagating
+    /// addition and enforces that the sum is within the bounds of `I`. As such, the cost is `I::BITS` private variables
+    /// and `I::BITS + 1` constraints, regardless of the number of operands.
+    ///
+    /// Note that overflow is checked against the true sum of `values`, not against each partial sum, so unlike a fold
+    /// of `add_checked`, this does not halt (or become unsatisfiable) on a transient overflow of a partial sum
+    /// (e.g. `[i8::MAX, 1, -1]`). This requires `values.len() * 2^I::BITS` to be less than the base field modulus.
+    pub fn add_many(values: &[Self]) -> Self {
+        match values {
+            [] => Self::zero(),
+            [value] => value.clone(),
+            _ => {
+                // Accumulate the operands in the base field.
+                let sum = values.iter().fold(Field::<E>::zero(), |sum, value| sum + value.to_field());
+
+                // For signed integers, reinterpret the sum of the two's complement representations
+                // as `sum + 2^(I::BITS - 1)`, which is within [0, 2^I::BITS) if and only if the sum does not overflow.
+                let sum = match I::is_signed() {
+                    true => {
+                        let two_pow_bits_minus_one = (1..I::BITS).fold(Field::<E>::one(), |power, _| power.double());
+                        let two_pow_bits = two_pow_bits_minus_one.double();
+                        let num_negative = values
+                            .iter()
+                            .fold(Field::<E>::zero(), |count, value| count + Field::from_boolean(value.msb()));
+                        sum - num_negative * two_pow_bits + two_pow_bits_minus_one
+                    }
+                    false => sum,
+                };
+
+                // Ensure the sum of constants does not overflow.
+                if sum.is_constant() && sum.eject_value().to_bits_le().iter().skip(I::BITS as usize).any(|bit| *bit) {
+                    E::halt("Integer overflow on addition of constants")
+                }
+
+                // Extract the bits of the sum, which enforces that the sum is within [0, 2^I::BITS).
+                let mut bits_le = sum.to_lower_bits_le(I::BITS as usize);
+
+                // For signed integers, remove the offset of `2^(I::BITS - 1)` by flipping the most significant bit.
+                if I::is_signed() {
+                    if let Some(msb) = bits_le.last_mut() {
+                        *msb = !&*msb;
+                    }
+                }
+
+                Integer { bits_le, phantom: Default::default() }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const MAX_LENGTH: usize = 16;
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sample values small enough that no partial sum can overflow,
+        // so that the result must match a sequential fold of `add_checked`.
+        let (min, max) = match I::is_signed() {
+            true => (I::from(-8).unwrap(), I::from(7).unwrap()),
+            false => (I::zero(), I::from(15).unwrap()),
+        };
+
+        for length in 0..=MAX_LENGTH {
+            let values: Vec<_> =
+                (0..length).map(|_| Integer::<Circuit, I>::rand_bounded(mode, &mut rng, min, max)).collect();
+
+            // Compute the expected sum with a sequential fold of `add_checked`.
+            let expected = values.iter().fold(Integer::<Circuit, I>::zero(), |sum, value| sum.add_checked(value));
+
+            Circuit::scope(format!("AddMany: {mode} {length}"), || {
+                let candidate = Integer::add_many(&values);
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+
+        // Ensure an overflowing sum halts for constants, and is unsatisfiable otherwise.
+        let mut overflowing = vec![
+            vec![Integer::<Circuit, I>::new(mode, console::Integer::MAX), Integer::new(mode, console::Integer::one())],
+            vec![Integer::<Circuit, I>::new(mode, console::Integer::MAX); 3],
+        ];
+        if I::is_signed() {
+            let minus_one = console::Integer::new(I::zero().wrapping_sub(&I::one()));
+            overflowing.push(vec![Integer::new(mode, console::Integer::MIN), Integer::new(mode, minus_one)]);
+        }
+        for values in overflowing {
+            match mode.is_constant() {
+                true => check_unary_operation_halts(&values[..], Integer::add_many),
+                false => {
+                    let _candidate = Integer::add_many(&values);
+                    assert!(!Circuit::is_satisfied());
+                }
+            }
+            Circuit::reset();
+        }
+
+        // Ensure the sums at the bounds of the type are accepted.
+        for (values, expected) in [
+            (vec![console::Integer::MAX, console::Integer::zero()], console::Integer::MAX),
+            (vec![console::Integer::MIN, console::Integer::zero(), console::Integer::zero()], console::Integer::MIN),
+        ] {
+            let values: Vec<_> = values.into_iter().map(|value| Integer::<Circuit, I>::new(mode, value)).collect();
+            let candidate = Integer::add_many(&values);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn run_transient_overflow_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        // The partial sum `MAX + MAX` overflows, but the true sum `MAX + MAX + MIN + MIN = -2` does not.
+        let values = [
+            Integer::<Circuit, I>::new(mode, console::Integer::MAX),
+            Integer::new(mode, console::Integer::MAX),
+            Integer::new(mode, console::Integer::MIN),
+            Integer::new(mode, console::Integer::MIN),
+        ];
+        let expected = I::zero().wrapping_sub(&I::one()).wrapping_sub(&I::one());
+
+        let candidate = Integer::add_many(&values);
+        assert_eq!(expected, *candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn run_cost_test<I: IntegerType + RefUnwindSafe>() {
+        let mut rng = TestRng::default();
+
+        for length in [2, 4, 8, 16] {
+            let values: Vec<_> = (0..length)
+                .map(|_| Integer::<Circuit, I>::rand_bounded(Mode::Private, &mut rng, I::zero(), I::one()))
+                .collect();
+
+            // The batched sum costs a single decomposition, regardless of the number of operands.
+            Circuit::scope(format!("AddMany: {length}"), || {
+                let _candidate = Integer::add_many(&values);
+                assert_scope!(0, 0, I::BITS, I::BITS + 1);
+            });
+
+            // A sequential fold of `add_checked` costs one carry-propagating addition per operand after the first.
+            Circuit::scope(format!("AddChecked: {length}"), || {
+                let _candidate = values[1..].iter().fold(values[0].clone(), |sum, value| sum.add_checked(value));
+                let case = (Mode::Private, Mode::Private);
+                let count =
+                    count!(Integer<Circuit, I>, AddChecked<Integer<Circuit, I>, Output = Integer<Circuit, I>>, &case);
+                let num_additions = length as u64 - 1;
+                let expected = (1..num_additions).fold(count, |total, _| total + count);
+                assert!(expected.matches(
+                    Circuit::num_constants_in_scope(),
+                    Circuit::num_public_in_scope(),
+                    Circuit::num_private_in_scope(),
+                    Circuit::num_constraints_in_scope()
+                ));
+                // The savings grow linearly with the number of operands.
+                assert!(Circuit::num_constraints_in_scope() >= num_additions * (I::BITS + 1));
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_add_many_constant_costs() {
+        let values = vec![Integer::<Circuit, u8>::constant(console::Integer::new(7)); 5];
+        Circuit::scope("AddMany: constant", || {
+            let candidate = Integer::add_many(&values);
+            assert_eq!(35u8, *candidate.eject_value());
+            assert!(candidate.is_constant());
+            assert_scope!(8, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, add_many);
+    test_integer_unary!(run_test, i16, add_many);
+    test_integer_unary!(run_test, i32, add_many);
+    test_integer_unary!(run_test, i64, add_many);
+    test_integer_unary!(run_test, i128, add_many);
+
+    test_integer_unary!(run_test, u8, add_many);
+    test_integer_unary!(run_test, u16, add_many);
+    test_integer_unary!(run_test, u32, add_many);
+    test_integer_unary!(run_test, u64, add_many);
+    test_integer_unary!(run_test, u128, add_many);
+
+    test_integer_unary!(run_transient_overflow_test, i8, add_many_transient_overflow);
+    test_integer_unary!(run_transient_overflow_test, i16, add_many_transient_overflow);
+    test_integer_unary!(run_transient_overflow_test, i32, add_many_transient_overflow);
+    test_integer_unary!(run_transient_overflow_test, i64, add_many_transient_overflow);
+    test_integer_unary!(run_transient_overflow_test, i128, add_many_transient_overflow);
+
+    #[test]
+    fn test_add_many_costs() {
+        run_cost_test::<u8>();
+        run_cost_test::<u32>();
+        run_cost_test::<u128>();
+        run_cost_test::<i8>();
+        run_cost_test::<i32>();
+        run_cost_test::<i128>();
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> AddMod<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `(self + other) % modulus`, for a constant `modulus`.
+    /// This operation is only defined for unsigned integers.
+    ///
+    /// Note: This assumes that `self` and `other` are both less than `modulus`, and
+    /// reduces the sum with a single conditional subtraction. If the assumption does
+    /// not hold, the result may be unreduced, or the circuit may not be satisfied.
+    #[inline]
+    fn add_mod(&self, other: &Integer<E, I>, modulus: &Integer<E, I>) -> Self::Output {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("Modular addition is only defined for unsigned integers")
+        }
+        // Ensure the modulus is a nonzero constant.
+        if !modulus.is_constant() {
+            E::halt("The modulus of a modular addition must be a constant")
+        }
+        if *modulus.eject_value() == I::zero() {
+            E::halt("The modulus of a modular addition must be nonzero")
+        }
+
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Ensure the operands are reduced, as the sum is reduced with a single subtraction.
+            if self.eject_value() >= modulus.eject_value() || other.eject_value() >= modulus.eject_value() {
+                E::halt("The operands of a modular addition must be less than the modulus")
+            }
+            // Compute the reduced sum without overflowing, and return the new constant.
+            witness!(|self, other, modulus| {
+                let complement = *modulus - *other;
+                match *self >= complement {
+                    true => console::Integer::new(*self - complement),
+                    false => console::Integer::new(*self + *other),
+                }
+            })
+        } else {
+            // Compute the sum of `self` and `other` in the field, where it cannot overflow.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            let sum = self.to_field() + other.to_field();
+
+            // Compute `sum - modulus` as in `sub_wrapped`, where `!modulus + 1` is equal to `2^BITS - modulus`.
+            // As `self` and `other` are less than `modulus`, this fits in `BITS + 1` bits,
+            // and the carry bit is set if and only if `sum` is greater than or equal to `modulus`.
+            let difference = &sum + (!modulus).to_field() + Field::one();
+            let is_reducible = match difference.to_lower_bits_le(I::BITS as usize + 1).pop() {
+                Some(bit) => bit,
+                // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                None => E::halt("Malformed difference detected during modular addition"),
+            };
+
+            // Subtract the modulus once if the sum is at least the modulus.
+            let reduced = Field::ternary(&is_reducible, &(&sum - modulus.to_field()), &sum);
+
+            // Convert the reduced sum back into an integer.
+            Integer::from_field(reduced)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn AddMod<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (_, _) => Count::is(0, 0, 2 * I::BITS + 2, 2 * I::BITS + 4),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn AddMod<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 64;
+
+    fn check_add_mod<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        modulus: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let m = Integer::<Circuit, I>::constant(modulus);
+
+        // Compute the expected result with a reference modular addition.
+        let (first, second, modulus) =
+            (first.to_u128().unwrap(), second.to_u128().unwrap(), modulus.to_u128().unwrap());
+        let expected = match first.checked_add(second) {
+            Some(sum) => sum % modulus,
+            // Note: If the sum overflows a `u128`, it is less than `2 * modulus`, so a single subtraction reduces it.
+            None => first.wrapping_add(second).wrapping_sub(modulus),
+        };
+
+        Circuit::scope(name, || {
+            let candidate = a.add_mod(&b, &m);
+            assert_eq!(expected, candidate.eject_value().to_u128().unwrap());
+            assert_count!(AddMod(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(AddMod(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let two = I::one() + I::one();
+        let mut moduli = vec![I::one(), two, I::MAX, I::MAX / two + I::one()];
+        moduli.extend((0..4).map(|_| match I::rand(&mut rng) {
+            modulus if modulus.is_zero() => I::one(),
+            modulus => modulus,
+        }));
+
+        for modulus in moduli {
+            for i in 0..ITERATIONS {
+                let first = I::rand(&mut rng) % modulus;
+                let second = I::rand(&mut rng) % modulus;
+
+                let name = format!("AddMod: ({mode_a} + {mode_b}) % {modulus} {i}");
+                check_add_mod::<I>(
+                    &name,
+                    console::Integer::new(first),
+                    console::Integer::new(second),
+                    console::Integer::new(modulus),
+                    mode_a,
+                    mode_b,
+                );
+            }
+
+            // Check the largest operands.
+            let largest = console::Integer::new(modulus - I::one());
+            let name = format!("AddMod: ({mode_a} + {mode_b}) % {modulus} largest");
+            check_add_mod::<I>(&name, largest, largest, console::Integer::new(modulus), mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_add_mod_halts() {
+        let one = console::Integer::<_, u8>::one();
+        let zero = console::Integer::<_, u8>::zero();
+
+        // Ensure a non-constant modulus halts.
+        let a = Integer::<Circuit, u8>::new(Mode::Private, zero);
+        let m = Integer::<Circuit, u8>::new(Mode::Private, one + one);
+        check_operation_halts(&a, &m, |a, m| a.add_mod(a, m));
+
+        // Ensure a zero modulus halts.
+        let m = Integer::<Circuit, u8>::constant(zero);
+        check_operation_halts(&a, &m, |a, m| a.add_mod(a, m));
+
+        // Ensure unreduced constant operands halt.
+        let a = Integer::<Circuit, u8>::constant(one + one);
+        let m = Integer::<Circuit, u8>::constant(one + one);
+        check_operation_halts(&a, &m, |a, m| a.add_mod(a, m));
+
+        // Ensure a signed integer type halts.
+        let a = Integer::<Circuit, i8>::new(Mode::Private, console::Integer::zero());
+        let m = Integer::<Circuit, i8>::constant(console::Integer::one());
+        check_operation_halts(&a, &m, |a, m| a.add_mod(a, m));
+
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, u8, add_mod);
+    test_integer_binary!(run_test, u16, add_mod);
+    test_integer_binary!(run_test, u32, add_mod);
+    test_integer_binary!(run_test, u64, add_mod);
+    test_integer_binary!(run_test, u128, add_mod);
+}
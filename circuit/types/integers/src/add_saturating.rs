@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> AddSaturating<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn add_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the sum and return the new constant.
+            witness!(|self, other| console::Integer::new(self.saturating_add(*other)))
+        } else {
+            // Compute the wrapped sum of `self` and `other`, along with an overflow flag.
+            let (sum, is_overflow) = self.overflowing_add(other);
+
+            // Determine the saturation bound.
+            let bound = match I::is_signed() {
+                // For signed addition, the bound is `I::MIN` if `self` is negative, and `I::MAX` otherwise.
+                // Note: The bound is constructed directly from the sign of `self`, as `I::MIN` and `I::MAX` are
+                // the integers whose bits are all equal to the negation of the MSB, except for the MSB itself.
+                true => {
+                    let mut bound_bits_le = vec![!self.msb(); I::BITS as usize - 1];
+                    bound_bits_le.push(self.msb().clone());
+                    Integer { bits_le: bound_bits_le, phantom: Default::default() }
+                }
+                // For unsigned addition, the bound is `I::MAX`.
+                false => Integer::constant(console::Integer::MAX),
+            };
+
+            Self::ternary(&is_overflow, &bound, &sum)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn AddSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match I::is_signed() {
+            true => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (Mode::Constant, _) => Count::is(0, 0, 2 * I::BITS + 2, 2 * I::BITS + 3),
+                (_, Mode::Constant) => Count::is(0, 0, 2 * I::BITS + 3, 2 * I::BITS + 4),
+                (_, _) => Count::is(0, 0, 2 * I::BITS + 4, 2 * I::BITS + 5),
+            },
+            false => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (_, _) => Count::is(I::BITS, 0, 2 * I::BITS + 1, 2 * I::BITS + 2),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn AddSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_add<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::new(mode_b, second);
+        let expected = (*first).saturating_add(*second);
+        Circuit::scope(name, || {
+            let candidate = a.add_saturating(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(AddSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(AddSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("AddSaturating: {mode_a} + {mode_b} {i}");
+            check_add::<I>(&name, first, second, mode_a, mode_b);
+            check_add::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+        }
+
+        // Overflow
+        check_add::<I>("MAX + 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_add::<I>("1 + MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+        check_add::<I>("MAX + MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+
+        // Underflow
+        if I::is_signed() {
+            check_add::<I>("MIN + (-1)", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_add::<I>("-1 + MIN", -console::Integer::one(), console::Integer::MIN, mode_a, mode_b);
+            check_add::<I>("MIN + MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+            check_add::<I>("MIN + MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("AddSaturating: ({first} + {second})");
+                check_add::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, plus);
+    test_integer_binary!(run_test, i16, plus);
+    test_integer_binary!(run_test, i32, plus);
+    test_integer_binary!(run_test, i64, plus);
+    test_integer_binary!(run_test, i128, plus);
+
+    test_integer_binary!(run_test, u8, plus);
+    test_integer_binary!(run_test, u16, plus);
+    test_integer_binary!(run_test, u32, plus);
+    test_integer_binary!(run_test, u64, plus);
+    test_integer_binary!(run_test, u128, plus);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, plus, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, plus, exhaustive);
+}
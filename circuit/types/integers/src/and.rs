@@ -113,7 +113,9 @@ mod tests {
     use super::*;
     use snarkvm_circuit_environment::Circuit;
 
-    use std::ops::RangeInclusive;
+    use test_utilities::*;
+
+    use std::{ops::RangeInclusive, panic::RefUnwindSafe};
 
     const ITERATIONS: u64 = 128;
 
@@ -161,6 +163,27 @@ mod tests {
         check_and::<I>("MIN & 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
     }
 
+    fn run_operator_test<I: IntegerType + BitAnd<Output = I> + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let forms: [BinaryOperator<I>; 6] = binary_operator_forms!(&, &=);
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let a = Integer::<Circuit, I>::new(mode_a, first);
+            let b = Integer::new(mode_b, second);
+            let check = |candidate: &Integer<Circuit, I>| {
+                assert_count!(BitAnd(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                assert_output_mode!(BitAnd(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)), candidate);
+            };
+
+            let name = format!("BitAndOperator: ({mode_a} & {mode_b}) {i}");
+            check_binary_operator_forms(&name, &forms, &a, &b, Some(first & second), check);
+        }
+    }
+
     fn run_exhaustive_test<I: IntegerType + BitAnd<Output = I>>(mode_a: Mode, mode_b: Mode)
     where
         RangeInclusive<I>: Iterator<Item = I>,
@@ -188,6 +211,9 @@ mod tests {
     test_integer_binary!(run_test, u64, bitand);
     test_integer_binary!(run_test, u128, bitand);
 
+    test_integer_binary!(run_operator_test, i16, bitand_operator);
+    test_integer_binary!(run_operator_test, u64, bitand_operator);
+
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, bitand, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, bitand, exhaustive);
 }
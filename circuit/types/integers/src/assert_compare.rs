@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Enforces that `self < other`.
+    ///
+    /// If both operands are constant, this halts if the relation does not hold.
+    /// Otherwise, this fails to satisfy the circuit if the relation does not hold.
+    pub fn assert_less_than(&self, other: &Self) {
+        match self.is_constant() && other.is_constant() {
+            true if self.eject_value() >= other.eject_value() => {
+                E::halt(format!("Expected {} < {}", self.eject_value(), other.eject_value()))
+            }
+            true => (),
+            false => E::assert(self.is_less_than(other)),
+        }
+    }
+
+    /// Enforces that `self > other`.
+    ///
+    /// If both operands are constant, this halts if the relation does not hold.
+    /// Otherwise, this fails to satisfy the circuit if the relation does not hold.
+    pub fn assert_greater_than(&self, other: &Self) {
+        other.assert_less_than(self)
+    }
+
+    /// Enforces that `self <= other`.
+    ///
+    /// If both operands are constant, this halts if the relation does not hold.
+    /// Otherwise, this fails to satisfy the circuit if the relation does not hold.
+    pub fn assert_less_or_equal(&self, other: &Self) {
+        match self.is_constant() && other.is_constant() {
+            true if self.eject_value() > other.eject_value() => {
+                E::halt(format!("Expected {} <= {}", self.eject_value(), other.eject_value()))
+            }
+            true => (),
+            false => E::assert(self.is_less_than_or_equal(other)),
+        }
+    }
+
+    /// Enforces that `self >= other`.
+    ///
+    /// If both operands are constant, this halts if the relation does not hold.
+    /// Otherwise, this fails to satisfy the circuit if the relation does not hold.
+    pub fn assert_greater_or_equal(&self, other: &Self) {
+        other.assert_less_or_equal(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope_fails, Circuit};
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    /// Checks the given assertion is satisfied if and only if `expected` is `true`, and checks its cost.
+    fn check_assertion<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        expected: bool,
+        a: &Integer<Circuit, I>,
+        b: &Integer<Circuit, I>,
+        assertion: fn(&Integer<Circuit, I>, &Integer<Circuit, I>),
+    ) {
+        match (expected, a.is_constant() && b.is_constant()) {
+            (false, true) => {
+                let result = std::panic::catch_unwind(|| assertion(a, b));
+                assert!(result.is_err());
+            }
+            (_, _) => Circuit::scope(name, || {
+                assertion(a, b);
+                // The cost is that of the comparison, along with one constraint to enforce it.
+                let (num_constants, num_public, num_private, num_constraints) =
+                    match (I::is_signed(), a.eject_mode(), b.eject_mode()) {
+                        (_, Mode::Constant, Mode::Constant) => (0, 0, 0, 0),
+                        (true, Mode::Constant, _) | (true, _, Mode::Constant) => (I::BITS, 0, I::BITS + 2, I::BITS + 4),
+                        (true, _, _) => (I::BITS, 0, I::BITS + 4, I::BITS + 6),
+                        (false, _, _) => (I::BITS, 0, I::BITS + 1, I::BITS + 3),
+                    };
+                match expected {
+                    true => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                    false => assert_scope_fails!(num_constants, num_public, num_private, num_constraints),
+                }
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn check_assert_compare<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        check_assertion(&format!("{name} <"), first < second, &a, &b, Integer::assert_less_than);
+        check_assertion(&format!("{name} >"), first > second, &a, &b, Integer::assert_greater_than);
+        check_assertion(&format!("{name} <="), first <= second, &a, &b, Integer::assert_less_or_equal);
+        check_assertion(&format!("{name} >="), first >= second, &a, &b, Integer::assert_greater_or_equal);
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("AssertCompare: ({mode_a}, {mode_b}) {i}");
+            check_assert_compare::<I>(&name, first, second, mode_a, mode_b);
+            check_assert_compare::<I>(&name, second, first, mode_a, mode_b);
+            check_assert_compare::<I>(&name, first, first, mode_a, mode_b);
+        }
+
+        // Check the bounds.
+        check_assert_compare::<I>("MIN, MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        check_assert_compare::<I>("MAX, MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("AssertCompare: ({first}, {second})");
+                check_assert_compare::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, assert_compare);
+    test_integer_binary!(run_test, i16, assert_compare);
+    test_integer_binary!(run_test, i32, assert_compare);
+    test_integer_binary!(run_test, i64, assert_compare);
+    test_integer_binary!(run_test, i128, assert_compare);
+
+    test_integer_binary!(run_test, u8, assert_compare);
+    test_integer_binary!(run_test, u16, assert_compare);
+    test_integer_binary!(run_test, u32, assert_compare);
+    test_integer_binary!(run_test, u64, assert_compare);
+    test_integer_binary!(run_test, u128, assert_compare);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, assert_compare, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, assert_compare, exhaustive);
+}
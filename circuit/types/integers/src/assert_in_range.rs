@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Enforces that `lower <= self <= upper`.
+    ///
+    /// If all operands are constant, the range is checked directly, and this halts if `self` is out of range.
+    /// Otherwise, this fails to satisfy the circuit if `self` is out of range.
+    pub fn assert_in_range(&self, lower: &Self, upper: &Self) {
+        if self.is_constant() && lower.is_constant() && upper.is_constant() {
+            let (value, lower, upper) = (self.eject_value(), lower.eject_value(), upper.eject_value());
+            if value < lower || value > upper {
+                E::halt(format!("Integer {value} is not in the range [{lower}, {upper}]"))
+            }
+        } else {
+            E::assert(self.is_greater_than_or_equal(lower));
+            E::assert(self.is_less_than_or_equal(upper));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope_fails, Circuit};
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 16;
+
+    fn check_assert_in_range<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        lower: console::Integer<<Circuit as Environment>::Network, I>,
+        upper: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+        mode_c: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, value);
+        let b = Integer::<Circuit, I>::new(mode_b, lower);
+        let c = Integer::<Circuit, I>::new(mode_c, upper);
+
+        let is_in_range = lower <= value && value <= upper;
+        // Note: A violated bound halts if the bound and the value are both constant.
+        let is_below_constant = value < lower && mode_a.is_constant() && mode_b.is_constant();
+        let is_above_constant = value > upper && mode_a.is_constant() && mode_c.is_constant();
+        match is_below_constant || is_above_constant {
+            true => {
+                let result = std::panic::catch_unwind(|| a.assert_in_range(&b, &c));
+                assert!(result.is_err());
+            }
+            false => Circuit::scope(name, || {
+                a.assert_in_range(&b, &c);
+                // Check the cost of range-checking a variable against constant bounds, and against variable bounds.
+                let counts = match (I::is_signed(), mode_a, mode_b, mode_c) {
+                    (_, Mode::Constant, Mode::Constant, Mode::Constant) => Some((0, 0, 0, 0)),
+                    (true, Mode::Public | Mode::Private, Mode::Constant, Mode::Constant) => {
+                        Some((2 * I::BITS, 0, 2 * I::BITS + 4, 2 * I::BITS + 8))
+                    }
+                    (
+                        true,
+                        Mode::Public | Mode::Private,
+                        Mode::Public | Mode::Private,
+                        Mode::Public | Mode::Private,
+                    ) => Some((2 * I::BITS, 0, 2 * I::BITS + 8, 2 * I::BITS + 12)),
+                    (false, Mode::Public | Mode::Private, _, _) => {
+                        Some((2 * I::BITS, 0, 2 * I::BITS + 2, 2 * I::BITS + 6))
+                    }
+                    _ => None,
+                };
+                match (is_in_range, counts) {
+                    (true, Some((c, p, v, k))) => assert_scope!(c, p, v, k),
+                    (false, Some((c, p, v, k))) => assert_scope_fails!(c, p, v, k),
+                    (true, None) => assert_scope!(),
+                    (false, None) => assert_scope_fails!(),
+                }
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let (lower, upper) = match first <= second {
+                true => (first, second),
+                false => (second, first),
+            };
+
+            let name = format!("AssertInRange: ({mode_a}, {mode_b}, {mode_c}) {i}");
+            check_assert_in_range::<I>(&name, value, lower, upper, mode_a, mode_b, mode_c);
+
+            // Check values at and beyond the bounds.
+            check_assert_in_range::<I>(&name, lower, lower, upper, mode_a, mode_b, mode_c);
+            check_assert_in_range::<I>(&name, upper, lower, upper, mode_a, mode_b, mode_c);
+            check_assert_in_range::<I>(&name, console::Integer::MIN, lower, upper, mode_a, mode_b, mode_c);
+            check_assert_in_range::<I>(&name, console::Integer::MAX, lower, upper, mode_a, mode_b, mode_c);
+
+            // Check an empty range, which no value satisfies.
+            if lower != upper {
+                check_assert_in_range::<I>(&name, value, upper, lower, mode_a, mode_b, mode_c);
+            }
+        }
+    }
+
+    fn run_bounded_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            let second: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            let (lower, upper) = match first <= second {
+                true => (*first, *second),
+                false => (*second, *first),
+            };
+            let b = Integer::<Circuit, I>::new(mode_b, console::Integer::new(lower));
+            let c = Integer::<Circuit, I>::new(mode_c, console::Integer::new(upper));
+
+            // Sample a value within the range, which must satisfy the assertion.
+            let a = Integer::<Circuit, I>::rand_bounded(mode_a, &mut rng, lower, upper);
+            Circuit::scope(format!("AssertInRange: ({mode_a}, {mode_b}, {mode_c}) {i} within"), || {
+                a.assert_in_range(&b, &c);
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+
+            // Sample values below and above the range, which must halt if the violated bound and the value
+            // are both constant, and must not satisfy the assertion otherwise.
+            let mut outside = Vec::with_capacity(2);
+            if lower > I::MIN {
+                let a = Integer::<Circuit, I>::rand_bounded(mode_a, &mut rng, I::MIN, lower - I::one());
+                outside.push((a, mode_a.is_constant() && mode_b.is_constant()));
+            }
+            if upper < I::MAX {
+                let a = Integer::<Circuit, I>::rand_bounded(mode_a, &mut rng, upper + I::one(), I::MAX);
+                outside.push((a, mode_a.is_constant() && mode_c.is_constant()));
+            }
+            for (a, is_static) in outside {
+                match is_static {
+                    true => {
+                        let result = std::panic::catch_unwind(|| a.assert_in_range(&b, &c));
+                        assert!(result.is_err());
+                    }
+                    false => {
+                        Circuit::scope(format!("AssertInRange: ({mode_a}, {mode_b}, {mode_c}) {i} outside"), || {
+                            a.assert_in_range(&b, &c);
+                            assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                        })
+                    }
+                }
+            }
+            Circuit::reset();
+        }
+    }
+
+    test_integer_ternary!(run_test, i8, value, lower, upper);
+    test_integer_ternary!(run_test, i16, value, lower, upper);
+    test_integer_ternary!(run_test, i32, value, lower, upper);
+    test_integer_ternary!(run_test, i64, value, lower, upper);
+    test_integer_ternary!(run_test, i128, value, lower, upper);
+
+    test_integer_ternary!(run_test, u8, value, lower, upper);
+    test_integer_ternary!(run_test, u16, value, lower, upper);
+    test_integer_ternary!(run_test, u32, value, lower, upper);
+    test_integer_ternary!(run_test, u64, value, lower, upper);
+    test_integer_ternary!(run_test, u128, value, lower, upper);
+
+    test_integer_ternary!(run_bounded_test, i8, bounded_value, lower, upper);
+    test_integer_ternary!(run_bounded_test, i32, bounded_value, lower, upper);
+    test_integer_ternary!(run_bounded_test, i128, bounded_value, lower, upper);
+
+    test_integer_ternary!(run_bounded_test, u8, bounded_value, lower, upper);
+    test_integer_ternary!(run_bounded_test, u32, bounded_value, lower, upper);
+    test_integer_ternary!(run_bounded_test, u128, bounded_value, lower, upper);
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns, for each bit position in little-endian order, the number of integers in `values` with that bit set.
+    pub fn bit_frequency(values: &[Self]) -> Vec<U32<E>> {
+        // Ensure the number of integers does not exceed the maximum count.
+        if values.len() as u64 > u32::MAX as u64 {
+            E::halt("The number of integers exceeds the maximum bit frequency")
+        }
+
+        (0..I::BITS as usize)
+            .map(|index| {
+                // Instead of adding the bits with integer addition, sum the bits at this position in the base field.
+                // Note: This is safe as the number of integers is less than the base field modulus.
+                let count =
+                    values.iter().fold(Field::zero(), |sum, value| sum + Field::from_boolean(&value.bits_le[index]));
+                // Decompose the sum into an unsigned 32-bit integer.
+                U32::from_field(count)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_bit_frequency<I: IntegerType>(
+        name: &str,
+        values: &[console::Integer<<Circuit as Environment>::Network, I>],
+        mode: Mode,
+    ) {
+        let candidates = values.iter().map(|value| Integer::<Circuit, I>::new(mode, *value)).collect::<Vec<_>>();
+
+        // Compute the expected frequency of each bit position.
+        let expected = (0..I::BITS)
+            .map(|index| {
+                values.iter().filter(|value| (***value >> index as usize) & I::one() == I::one()).count() as u32
+            })
+            .collect::<Vec<_>>();
+
+        Circuit::scope(name, || {
+            let candidate = Integer::bit_frequency(&candidates);
+            assert_eq!(expected, candidate.iter().map(|count| *count.eject_value()).collect::<Vec<_>>());
+            match mode.is_constant() {
+                true => assert_scope!(I::BITS * 32, 0, 0, 0),
+                false => assert_scope!(0, 0, I::BITS * 32, I::BITS * 33),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let num_values = 1 + (u8::rand(&mut rng) % 16) as usize;
+            let values = (0..num_values).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+
+            let name = format!("BitFrequency: {mode} {i}");
+            check_bit_frequency::<I>(&name, &values, mode);
+        }
+
+        // Check the MIN and MAX cases.
+        let name = format!("BitFrequency: {mode} bounds");
+        check_bit_frequency::<I>(&name, &[console::Integer::MIN, console::Integer::MAX, console::Integer::MAX], mode);
+    }
+
+    test_integer_unary!(run_test, i8, bit_frequency);
+    test_integer_unary!(run_test, i16, bit_frequency);
+    test_integer_unary!(run_test, i32, bit_frequency);
+    test_integer_unary!(run_test, i64, bit_frequency);
+    test_integer_unary!(run_test, i128, bit_frequency);
+
+    test_integer_unary!(run_test, u8, bit_frequency);
+    test_integer_unary!(run_test, u16, bit_frequency);
+    test_integer_unary!(run_test, u32, bit_frequency);
+    test_integer_unary!(run_test, u64, bit_frequency);
+    test_integer_unary!(run_test, u128, bit_frequency);
+}
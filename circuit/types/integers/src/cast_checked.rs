@@ -0,0 +1,205 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I0: IntegerType, I1: IntegerType> CastChecked<Integer<E, I1>> for Integer<E, I0> {
+    /// Casts an `Integer` to another `Integer`, enforcing the value is representable in the target type.
+    #[inline]
+    fn cast_checked(&self) -> Integer<E, I1> {
+        let mut bits_le = self.bits_le.clone();
+        match (I0::is_signed(), I1::is_signed()) {
+            // If the two types are both unsigned, instantiate the new integer from the bits.
+            // Note: `from_bits_le` checks that any excess bits are zero.
+            (false, false) => Integer::<E, I1>::from_bits_le(&bits_le),
+            // If the source type is unsigned and the destination type is signed, perform the required checks.
+            (false, true) => match I0::BITS < I1::BITS {
+                // If the source type is smaller than the destination type, instantiate the new integer from the bits.
+                true => Integer::<E, I1>::from_bits_le(&bits_le),
+                // If the source type is the same size or larger than the destination type, check that the most significant bits are zero.
+                // Then instantiate the new integer from the lower bits.
+                false => {
+                    Boolean::assert_bits_are_zero(&bits_le[(I1::BITS.saturating_sub(1) as usize)..]);
+                    Integer::<E, I1>::from_bits_le(&bits_le[..(I1::BITS as usize)])
+                }
+            },
+            // If the source type is signed and the destination type is unsigned, perform the required checks.
+            (true, false) => match I0::BITS <= I1::BITS {
+                // If the source type is smaller than or equal to the destination type, check that the most significant bit is zero.
+                // Then instantiate the new integer from the lower bits.
+                true => {
+                    E::assert(!&bits_le[I0::BITS.saturating_sub(1) as usize]);
+                    Integer::<E, I1>::from_bits_le(&bits_le)
+                }
+                // If the source type is larger than the destination type, check that the upper bits are zero.
+                // Then instantiate the new integer from the lower bits.
+                false => {
+                    Boolean::assert_bits_are_zero(&bits_le[(I1::BITS as usize)..]);
+                    Integer::<E, I1>::from_bits_le(&bits_le[..(I1::BITS as usize)])
+                }
+            },
+            // If the two types are both signed, perform the required checks.
+            (true, true) => match I0::BITS <= I1::BITS {
+                // If the source type is smaller than or equal to the destination type, sign extend the source integer.
+                // Then instantiate the new integer from the bits.
+                true => {
+                    bits_le.resize(I1::BITS as usize, self.msb().clone());
+                    Integer::<E, I1>::from_bits_le(&bits_le)
+                }
+                // If the source type is larger than the destination type, check that the upper bits match the most significant bit.
+                // Then instantiate the new integer from the appropriate lower bits.
+                false => {
+                    // Get the most significant bit.
+                    let msb = match bits_le.pop() {
+                        Some(bit) => bit,
+                        None => E::halt("Failed to extract the MSB from the integer."),
+                    };
+                    // Check that the upper bits match the most significant bit.
+                    let upper_bits = bits_le.iter().skip(I1::BITS.saturating_sub(1) as usize);
+                    for bit in upper_bits {
+                        E::assert_eq(&msb, bit);
+                    }
+                    // Instantiate the new integer from the lower bits and the most significant bit.
+                    let mut lower_bits: Vec<_> =
+                        bits_le.into_iter().take(I1::BITS.saturating_sub(1) as usize).collect();
+                    lower_bits.push(msb);
+                    Integer::<E, I1>::from_bits_le(&lower_bits)
+                }
+            },
+        }
+    }
+}
+
+impl<E: Environment, I0: IntegerType, I1: IntegerType> Metrics<dyn CastChecked<Integer<E, I1>>> for Integer<E, I0> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(0, 0, 0, 0),
+            _ => match (I0::is_signed(), I1::is_signed()) {
+                // Narrowing requires a single constraint to check the excess bits are zero.
+                (false, false) => match I0::BITS <= I1::BITS {
+                    true => Count::is(0, 0, 0, 0),
+                    false => Count::is(0, 0, 0, 1),
+                },
+                // Casting to a signed type of the same size or smaller requires the excess bits to be zero.
+                (false, true) => match I0::BITS < I1::BITS {
+                    true => Count::is(0, 0, 0, 0),
+                    false => Count::is(0, 0, 0, 1),
+                },
+                // Casting to an unsigned type requires the value to be non-negative, and the excess bits to be zero.
+                (true, false) => Count::is(0, 0, 0, 1),
+                // Narrowing requires each excess bit to match the most significant bit.
+                (true, true) => Count::is(0, 0, 0, I0::BITS.saturating_sub(I1::BITS)),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I0: IntegerType, I1: IntegerType> OutputMode<dyn CastChecked<Integer<E, I1>>> for Integer<E, I0> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        *case
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::{RefUnwindSafe, UnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_cast_checked<I0: IntegerType + RefUnwindSafe + UnwindSafe, I1: IntegerType + RefUnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I0>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I0>::new(mode, value);
+        match I1::from(*value) {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate: Integer<Circuit, I1> = a.cast_checked();
+                assert_eq!(expected, *candidate.eject_value());
+                assert_count!(Integer<Circuit, I0>, CastChecked<Integer<Circuit, I1>>, &mode);
+                assert_output_mode!(Integer<Circuit, I0>, CastChecked<Integer<Circuit, I1>>, &mode, candidate);
+            }),
+            None => match mode {
+                Mode::Constant => check_unary_operation_halts(a, |a: Integer<Circuit, I0>| {
+                    CastChecked::<Integer<Circuit, I1>>::cast_checked(&a)
+                }),
+                _ => Circuit::scope(name, || {
+                    let _candidate: Integer<Circuit, I1> = a.cast_checked();
+                    assert_count_fails!(Integer<Circuit, I0>, CastChecked<Integer<Circuit, I1>>, &mode);
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I0: IntegerType + RefUnwindSafe + UnwindSafe, I1: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("CastChecked: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_cast_checked::<I0, I1>(&name, value, mode);
+        }
+
+        // Check the bounds of the source type.
+        for value in [I0::zero(), I0::one(), I0::MAX, I0::MIN, I0::zero().wrapping_sub(&I0::one())] {
+            let name = format!("CastChecked: {mode} {value}");
+            check_cast_checked::<I0, I1>(&name, console::Integer::new(value), mode);
+        }
+
+        // Check the bounds of the target type, and their neighbors, if they are representable in the source type.
+        for bound in [I0::from(I1::MAX), I0::from(I1::MIN)].into_iter().flatten() {
+            for value in [bound, bound.wrapping_add(&I0::one()), bound.wrapping_sub(&I0::one())] {
+                let name = format!("CastChecked: {mode} {value}");
+                check_cast_checked::<I0, I1>(&name, console::Integer::new(value), mode);
+            }
+        }
+    }
+
+    macro_rules! test_cast_checked {
+        ($source:ident => $($target:ident),+) => {
+            paste::paste! {
+                $(
+                    #[test]
+                    fn [<test_ $source _cast_checked_to_ $target>]() {
+                        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                            run_test::<$source, $target>(mode);
+                        }
+                    }
+                )+
+            }
+        };
+    }
+
+    test_cast_checked!(i8 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_checked!(i16 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_checked!(i32 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_checked!(i64 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_checked!(i128 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+    test_cast_checked!(u8 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_checked!(u16 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_checked!(u32 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_checked!(u64 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_checked!(u128 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+}
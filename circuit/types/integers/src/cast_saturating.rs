@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I0: IntegerType, I1: IntegerType> CastSaturating<Integer<E, I1>> for Integer<E, I0> {
+    /// Casts an `Integer` to another `Integer`, bounding the value to `MAX` or `MIN` of the target type
+    /// if it is out of range.
+    #[inline]
+    fn cast_saturating(&self) -> Integer<E, I1> {
+        // Determine the number of magnitude bits (excluding the sign bit) in the source and target types.
+        let source_bits = I0::BITS as usize - I0::is_signed() as usize;
+        let target_bits = I1::BITS as usize - I1::is_signed() as usize;
+
+        // Retrieve the sign of `self`.
+        let is_negative = match I0::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        };
+
+        // Retrieve the magnitude bits of `self` which do not fit in the target type.
+        // Note: If the target type has at least as many magnitude bits as the source type, this is empty.
+        let upper_bits = self.bits_le.get(target_bits..source_bits).unwrap_or_default();
+
+        // A non-negative value is too large if and only if any of its upper bits are set.
+        let any_upper_bits = upper_bits.iter().fold(Boolean::constant(false), |any, bit| any | bit);
+        let is_too_large = !&is_negative & any_upper_bits;
+
+        // A negative value is too small for an unsigned target type.
+        // For a signed target type, it is too small if and only if its upper bits are not all set.
+        let is_too_small = match I1::is_signed() {
+            true => {
+                let all_upper_bits = upper_bits.iter().fold(Boolean::constant(true), |all, bit| all & bit);
+                is_negative & !all_upper_bits
+            }
+            false => is_negative,
+        };
+
+        // Return the bounded value, or the value truncated to the target type if it is in range.
+        let max = Integer::constant(console::Integer::MAX);
+        let min = Integer::constant(console::Integer::MIN);
+        Integer::ternary(&is_too_large, &max, &Integer::ternary(&is_too_small, &min, &self.cast_wrapped()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_cast_saturating<I0: IntegerType, I1: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I0>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I0>::new(mode, value);
+        let expected = match I1::from(*value) {
+            Some(expected) => expected,
+            None if *value < I0::zero() => I1::MIN,
+            None => I1::MAX,
+        };
+        Circuit::scope(name, || {
+            let candidate: Integer<Circuit, I1> = a.cast_saturating();
+            assert_eq!(expected, *candidate.eject_value());
+            assert_scope!();
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I0: IntegerType, I1: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("CastSaturating: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_cast_saturating::<I0, I1>(&name, value, mode);
+        }
+
+        // Check the bounds of the source type.
+        for value in [I0::zero(), I0::one(), I0::MAX, I0::MIN, I0::zero().wrapping_sub(&I0::one())] {
+            let name = format!("CastSaturating: {mode} {value}");
+            check_cast_saturating::<I0, I1>(&name, console::Integer::new(value), mode);
+        }
+
+        // Check the bounds of the target type, and their neighbors, if they are representable in the source type.
+        for bound in [I0::from(I1::MAX), I0::from(I1::MIN)].into_iter().flatten() {
+            for value in [bound, bound.wrapping_add(&I0::one()), bound.wrapping_sub(&I0::one())] {
+                let name = format!("CastSaturating: {mode} {value}");
+                check_cast_saturating::<I0, I1>(&name, console::Integer::new(value), mode);
+            }
+        }
+    }
+
+    macro_rules! test_cast_saturating {
+        ($source:ident => $($target:ident),+) => {
+            paste::paste! {
+                $(
+                    #[test]
+                    fn [<test_ $source _cast_saturating_to_ $target>]() {
+                        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                            run_test::<$source, $target>(mode);
+                        }
+                    }
+                )+
+            }
+        };
+    }
+
+    test_cast_saturating!(i8 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_saturating!(i16 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_saturating!(i32 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_saturating!(i64 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_saturating!(i128 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+    test_cast_saturating!(u8 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_saturating!(u16 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_saturating!(u32 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_saturating!(u64 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_saturating!(u128 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+}
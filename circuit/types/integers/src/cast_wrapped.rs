@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I0: IntegerType, I1: IntegerType> CastWrapped<Integer<E, I1>> for Integer<E, I0> {
+    /// Casts an `Integer` to another `Integer`, truncating or extending the bits as necessary.
+    /// This matches the semantics of the `as` operator on primitive integers.
+    #[inline]
+    fn cast_wrapped(&self) -> Integer<E, I1> {
+        let mut bits_le = self.bits_le.clone();
+        // If the source type is smaller than the destination type, then perform the appropriate extension.
+        let padding = match I0::is_signed() {
+            // If the source type is signed, then sign extend.
+            true => self.msb().clone(),
+            // Otherwise, zero extend.
+            false => Boolean::constant(false),
+        };
+        // Note: If the source type is larger than the destination type, this truncates the upper bits.
+        bits_le.resize(I1::BITS as usize, padding);
+        // Construct the integer from the bits.
+        Integer::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment, I0: IntegerType, I1: IntegerType> Metrics<dyn CastWrapped<Integer<E, I1>>> for Integer<E, I0> {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment, I0: IntegerType, I1: IntegerType> OutputMode<dyn CastWrapped<Integer<E, I1>>> for Integer<E, I0> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        *case
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_cast_wrapped<I0: IntegerType, I1: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I0>,
+        mode: Mode,
+        cast: fn(I0) -> I1,
+    ) {
+        let a = Integer::<Circuit, I0>::new(mode, value);
+        let expected = cast(*value);
+        Circuit::scope(name, || {
+            let candidate: Integer<Circuit, I1> = a.cast_wrapped();
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(Integer<Circuit, I0>, CastWrapped<Integer<Circuit, I1>>, &mode);
+            assert_output_mode!(Integer<Circuit, I0>, CastWrapped<Integer<Circuit, I1>>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I0: IntegerType, I1: IntegerType>(mode: Mode, cast: fn(I0) -> I1) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("CastWrapped: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_cast_wrapped::<I0, I1>(&name, value, mode, cast);
+        }
+
+        // Check the bounds of the source type.
+        for value in [I0::zero(), I0::one(), I0::MAX, I0::MIN, I0::zero().wrapping_sub(&I0::one())] {
+            let name = format!("CastWrapped: {mode} {value}");
+            check_cast_wrapped::<I0, I1>(&name, console::Integer::new(value), mode, cast);
+        }
+    }
+
+    macro_rules! test_cast_wrapped {
+        ($source:ident => $($target:ident),+) => {
+            paste::paste! {
+                $(
+                    #[test]
+                    #[allow(clippy::unnecessary_cast)]
+                    fn [<test_ $source _cast_wrapped_to_ $target>]() {
+                        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                            run_test::<$source, $target>(mode, |value| value as $target);
+                        }
+                    }
+                )+
+            }
+        };
+    }
+
+    test_cast_wrapped!(i8 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_wrapped!(i16 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_wrapped!(i32 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_wrapped!(i64 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_wrapped!(i128 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+    test_cast_wrapped!(u8 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_wrapped!(u16 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_wrapped!(u32 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_wrapped!(u64 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast_wrapped!(u128 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the bitwise choice of `y` and `z` by `x`, i.e. `(x & y) ^ (!x & z)`.
+    /// Each bit of the result is taken from `y` if the corresponding bit of `x` is set, and from `z` otherwise.
+    ///
+    /// This is equivalent to `select_bits`, which computes it with a single ternary per bit.
+    pub fn choose(x: &Self, y: &Self, z: &Self) -> Self {
+        Self::select_bits(x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_choose<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        third: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+        mode_c: Mode,
+    ) {
+        let x = Integer::<Circuit, I>::new(mode_a, first);
+        let y = Integer::<Circuit, I>::new(mode_b, second);
+        let z = Integer::<Circuit, I>::new(mode_c, third);
+
+        // Compute the expected value, where each bit is selected from `y` or `z` by the bit of `x`.
+        let mut expected = I::zero();
+        for i in 0..I::BITS as usize {
+            let bit = match (*first >> i) & I::one() == I::one() {
+                true => (*second >> i) & I::one(),
+                false => (*third >> i) & I::one(),
+            };
+            expected = expected | (bit << i);
+        }
+
+        Circuit::scope(name, || {
+            let candidate = Integer::choose(&x, &y, &z);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_scope!();
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let third = Uniform::rand(&mut rng);
+
+            let name = format!("Choose: ({mode_a}, {mode_b}, {mode_c}) {i}");
+            check_choose::<I>(&name, first, second, third, mode_a, mode_b, mode_c);
+
+            // Check that choosing between two equal inputs returns that input.
+            let name = format!("Choose Identity: ({mode_a}, {mode_b}, {mode_c}) {i}");
+            check_choose::<I>(&name, first, second, second, mode_a, mode_b, mode_c);
+        }
+
+        // Check that a selector of all zeros or all ones selects `z` or `y`, respectively.
+        let ones = console::Integer::new(I::zero().wrapping_sub(&I::one()));
+        let (zero, max, min) = (console::Integer::zero(), console::Integer::MAX, console::Integer::MIN);
+        check_choose::<I>("Choose: (0, MAX, MIN)", zero, max, min, mode_a, mode_b, mode_c);
+        check_choose::<I>("Choose: (1s, MAX, MIN)", ones, max, min, mode_a, mode_b, mode_c);
+    }
+
+    test_integer_ternary!(run_test, i8, choose, with, and);
+    test_integer_ternary!(run_test, i16, choose, with, and);
+    test_integer_ternary!(run_test, i32, choose, with, and);
+    test_integer_ternary!(run_test, i64, choose, with, and);
+    test_integer_ternary!(run_test, i128, choose, with, and);
+
+    test_integer_ternary!(run_test, u8, choose, with, and);
+    test_integer_ternary!(run_test, u16, choose, with, and);
+    test_integer_ternary!(run_test, u32, choose, with, and);
+    test_integer_ternary!(run_test, u64, choose, with, and);
+    test_integer_ternary!(run_test, u128, choose, with, and);
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Clamp<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self` restricted to the range `[lower, upper]`.
+    /// This operation halts, or fails to satisfy the circuit, if `lower > upper`.
+    fn clamp(&self, lower: &Self, upper: &Self) -> Self::Output {
+        // Ensure the lower bound is less than or equal to the upper bound.
+        match lower.is_constant() && upper.is_constant() {
+            true if lower.eject_value() > upper.eject_value() => {
+                E::halt("The lower bound of a clamp must be less than or equal to the upper bound")
+            }
+            true => (),
+            false => E::assert(lower.is_less_than_or_equal(upper)),
+        }
+
+        // Compute `max(lower, min(upper, self))`.
+        self.min(upper).max(lower)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Clamp<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (I::is_signed(), case.0, case.1, case.2) {
+            (_, Mode::Constant, Mode::Constant, Mode::Constant) => Count::is(2, 0, 0, 0),
+            (true, _, Mode::Constant, Mode::Constant) => Count::is(2 * I::BITS, 0, 4 * I::BITS + 4, 4 * I::BITS + 6),
+            (false, _, Mode::Constant, Mode::Constant) => Count::is(2 * I::BITS, 0, 4 * I::BITS + 2, 4 * I::BITS + 4),
+            (true, _, _, _) => Count::less_than(3 * I::BITS, 0, 5 * I::BITS + 12, 5 * I::BITS + 16),
+            (false, _, _, _) => Count::less_than(3 * I::BITS, 0, 5 * I::BITS + 3, 5 * I::BITS + 7),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Clamp<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (Mode::Constant, Mode::Constant, Mode::Constant) => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_clamp<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        lower: console::Integer<<Circuit as Environment>::Network, I>,
+        upper: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+        mode_c: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, value);
+        let b = Integer::<Circuit, I>::new(mode_b, lower);
+        let c = Integer::<Circuit, I>::new(mode_c, upper);
+
+        if lower > upper {
+            match (mode_b, mode_c) {
+                (Mode::Constant, Mode::Constant) => {
+                    check_operation_halts(&b, &c, |lower, upper| a.clamp(lower, upper));
+                }
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.clamp(&b, &c);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            }
+        } else {
+            let expected = Ord::clamp(*value, *lower, *upper);
+            Circuit::scope(name, || {
+                let candidate = a.clamp(&b, &c);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_count!(
+                    Integer<Circuit, I>,
+                    Clamp<Integer<Circuit, I>, Output = Integer<Circuit, I>>,
+                    &(mode_a, mode_b, mode_c)
+                );
+                assert_output_mode!(
+                    Integer<Circuit, I>,
+                    Clamp<Integer<Circuit, I>, Output = Integer<Circuit, I>>,
+                    &(mode_a, mode_b, mode_c),
+                    candidate
+                );
+            });
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let (lower, upper) = match first <= second {
+                true => (first, second),
+                false => (second, first),
+            };
+
+            let name = format!("Clamp: ({mode_a}, {mode_b}, {mode_c}) {i}");
+            check_clamp::<I>(&name, value, lower, upper, mode_a, mode_b, mode_c);
+
+            // Check values at and beyond the bounds.
+            check_clamp::<I>(&name, lower, lower, upper, mode_a, mode_b, mode_c);
+            check_clamp::<I>(&name, upper, lower, upper, mode_a, mode_b, mode_c);
+            check_clamp::<I>(&name, console::Integer::MIN, lower, upper, mode_a, mode_b, mode_c);
+            check_clamp::<I>(&name, console::Integer::MAX, lower, upper, mode_a, mode_b, mode_c);
+
+            // Check an empty range, and an invalid range.
+            check_clamp::<I>(&name, value, lower, lower, mode_a, mode_b, mode_c);
+            if lower != upper {
+                let name = format!("Clamp Invalid: ({mode_a}, {mode_b}, {mode_c}) {i}");
+                check_clamp::<I>(&name, value, upper, lower, mode_a, mode_b, mode_c);
+            }
+        }
+
+        // Check the sign boundary for signed integers.
+        if I::is_signed() {
+            let (zero, one) = (console::Integer::zero(), console::Integer::one());
+            check_clamp::<I>("Clamp: -1 in [0, 1]", -one, zero, one, mode_a, mode_b, mode_c);
+            check_clamp::<I>("Clamp: 1 in [-1, 0]", one, -one, zero, mode_a, mode_b, mode_c);
+            check_clamp::<I>("Clamp: MIN in [-1, 1]", console::Integer::MIN, -one, one, mode_a, mode_b, mode_c);
+        }
+    }
+
+    test_integer_ternary!(run_test, i8, clamp, within, and);
+    test_integer_ternary!(run_test, i16, clamp, within, and);
+    test_integer_ternary!(run_test, i32, clamp, within, and);
+    test_integer_ternary!(run_test, i64, clamp, within, and);
+    test_integer_ternary!(run_test, i128, clamp, within, and);
+
+    test_integer_ternary!(run_test, u8, clamp, within, and);
+    test_integer_ternary!(run_test, u16, clamp, within, and);
+    test_integer_ternary!(run_test, u32, clamp, within, and);
+    test_integer_ternary!(run_test, u64, clamp, within, and);
+    test_integer_ternary!(run_test, u128, clamp, within, and);
+}
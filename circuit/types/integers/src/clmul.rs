@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Clmul<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the lower half of the carry-less product of `self` and `other`.
+    ///
+    /// The partial products are computed with `AND`, and accumulated with `XOR`, as there are no carries
+    /// between bit positions. For non-constant operands, this costs `I::BITS^2` constraints.
+    #[inline]
+    fn clmul(&self, other: &Self) -> Self::Output {
+        Integer { bits_le: Self::clmul_bits_le(self, other, I::BITS as usize), phantom: Default::default() }
+    }
+
+    /// Returns the lower and upper halves of the carry-less product of `self` and `other`.
+    ///
+    /// The partial products are computed with `AND`, and accumulated with `XOR`, as there are no carries
+    /// between bit positions. For non-constant operands, this costs `2 * I::BITS^2 - 2 * I::BITS + 1` constraints.
+    #[inline]
+    fn clmul_wide(&self, other: &Self) -> (Self::Output, Self::Output) {
+        // Compute the `2 * I::BITS - 1` bits of the carry-less product, and pad the most significant bit with zero.
+        let mut bits_le = Self::clmul_bits_le(self, other, 2 * I::BITS as usize - 1);
+        bits_le.push(Boolean::constant(false));
+
+        // Split the bits into the lower and upper halves.
+        let upper_bits_le = bits_le.split_off(I::BITS as usize);
+        (Integer { bits_le, phantom: Default::default() }, Integer {
+            bits_le: upper_bits_le,
+            phantom: Default::default(),
+        })
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the lower `num_bits` bits of the carry-less product of `this` and `that`.
+    fn clmul_bits_le(this: &Self, that: &Self, num_bits: usize) -> Vec<Boolean<E>> {
+        (0..num_bits)
+            .map(|k| {
+                // The `k`-th bit is the XOR of the partial products `this[i] AND that[j]`, where `i + j = k`.
+                let start = k.saturating_sub(I::BITS as usize - 1);
+                let end = core::cmp::min(k, I::BITS as usize - 1);
+                (start..=end)
+                    .map(|i| &this.bits_le[i] & &that.bits_le[k - i])
+                    .reduce(|accumulator, partial_product| accumulator ^ partial_product)
+                    // Note: There is at least one partial product, as `k < 2 * I::BITS - 1`.
+                    .unwrap_or_else(|| Boolean::constant(false))
+            })
+            .collect()
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Clmul<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // Note: The partial products are free, and only the non-zero partial products are accumulated.
+            (Mode::Constant, _) | (_, Mode::Constant) => {
+                Count::less_than(0, 0, I::BITS * (I::BITS - 1) / 2, I::BITS * (I::BITS - 1) / 2)
+            }
+            (_, _) => Count::is(0, 0, I::BITS * I::BITS, I::BITS * I::BITS),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Clmul<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the bits of the carry-less product of `first` and `second`, computed by shifting and XORing.
+    fn reference_clmul<I: IntegerType>(
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+    ) -> Vec<bool> {
+        let mut product = vec![false; 2 * I::BITS as usize];
+        for (shift, bit) in second.to_bits_le().into_iter().enumerate() {
+            if bit {
+                for (i, first_bit) in first.to_bits_le().into_iter().enumerate() {
+                    product[i + shift] ^= first_bit;
+                }
+            }
+        }
+        product
+    }
+
+    fn check_clmul<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        let product = reference_clmul(first, second);
+        let (lower, upper) = product.split_at(I::BITS as usize);
+        let expected_lower = console::Integer::<_, I>::from_bits_le(lower).unwrap();
+        let expected_upper = console::Integer::<_, I>::from_bits_le(upper).unwrap();
+
+        Circuit::scope(name, || {
+            let candidate = a.clmul(&b);
+            assert_eq!(expected_lower, candidate.eject_value());
+            assert_count!(Clmul(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            // Note: A constant zero operand yields a constant product, so the output mode is only checked
+            // when the operands are either both constant or both non-constant.
+            if mode_a.is_constant() == mode_b.is_constant() {
+                assert_output_mode!(Clmul(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            }
+        });
+        Circuit::scope(name, || {
+            let (candidate_lower, candidate_upper) = a.clmul_wide(&b);
+            assert_eq!(expected_lower, candidate_lower.eject_value());
+            assert_eq!(expected_upper, candidate_upper.eject_value());
+            if !mode_a.is_constant() && !mode_b.is_constant() {
+                assert_eq!(2 * I::BITS * I::BITS - 2 * I::BITS + 1, Circuit::num_constraints_in_scope());
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Clmul: {mode_a} * {mode_b} {i}");
+            check_clmul::<I>(&name, first, second, mode_a, mode_b);
+            check_clmul::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+        }
+
+        check_clmul::<I>("0 * MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        check_clmul::<I>("1 * MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+        check_clmul::<I>("MAX * MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_clmul::<I>("MIN * MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_clmul_reference() {
+        type N = <Circuit as Environment>::Network;
+
+        // (x^2 + x + 1) * (x + 1) = x^3 + 1 over GF(2).
+        let a = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::<N, u8>::new(0b111));
+        let b = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::<N, u8>::new(0b11));
+        assert_eq!(0b1001, *a.clmul(&b).eject_value());
+
+        // (x^7 + 1) * (x^7 + x) = x^14 + x^8 + x^7 + x over GF(2).
+        let a = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::<N, u8>::new(0b1000_0001));
+        let b = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::<N, u8>::new(0b1000_0010));
+        let (lower, upper) = a.clmul_wide(&b);
+        assert_eq!(0b1000_0010, *lower.eject_value());
+        assert_eq!(0b0100_0001, *upper.eject_value());
+    }
+
+    test_integer_binary!(run_test, i8, clmul);
+    test_integer_binary!(run_test, i16, clmul);
+    test_integer_binary!(run_test, i32, clmul);
+    test_integer_binary!(run_test, i64, clmul);
+    test_integer_binary!(run_test, i128, clmul);
+
+    test_integer_binary!(run_test, u8, clmul);
+    test_integer_binary!(run_test, u16, clmul);
+    test_integer_binary!(run_test, u32, clmul);
+    test_integer_binary!(run_test, u64, clmul);
+    test_integer_binary!(run_test, u128, clmul);
+}
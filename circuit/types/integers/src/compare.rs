@@ -67,6 +67,70 @@ impl<E: Environment, I: IntegerType> Compare<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `true` if `self` is less than `constant`.
+    ///
+    /// Unlike `is_less_than`, this compares against the known bits of `constant` rather than allocating them,
+    /// which costs one constraint per bit above the lowest bit at which `self` can fall below `constant`.
+    /// Note that this synthesizes a different circuit than `is_less_than`, which is left unchanged.
+    /// If `constant` is not a constant, this is equivalent to `is_less_than`.
+    pub fn is_less_than_constant(&self, constant: &Self) -> Boolean<E> {
+        match self.is_constant() || !constant.is_constant() {
+            true => self.is_less_than(constant),
+            false => self.is_beyond_constant(constant, true),
+        }
+    }
+
+    /// Returns `true` if `self` is greater than `constant`.
+    ///
+    /// As with `is_less_than_constant`, this costs one constraint per bit above the lowest deciding bit of `constant`.
+    /// If `constant` is not a constant, this is equivalent to `is_greater_than`.
+    pub fn is_greater_than_constant(&self, constant: &Self) -> Boolean<E> {
+        match self.is_constant() || !constant.is_constant() {
+            true => self.is_greater_than(constant),
+            false => self.is_beyond_constant(constant, false),
+        }
+    }
+
+    /// Returns `true` if `self` is less than or equal to `constant`.
+    /// If `constant` is not a constant, this is equivalent to `is_less_than_or_equal`.
+    pub fn is_less_than_or_equal_constant(&self, constant: &Self) -> Boolean<E> {
+        !self.is_greater_than_constant(constant)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `constant`.
+    /// If `constant` is not a constant, this is equivalent to `is_greater_than_or_equal`.
+    pub fn is_greater_than_or_equal_constant(&self, constant: &Self) -> Boolean<E> {
+        !self.is_less_than_constant(constant)
+    }
+
+    /// Returns `self < constant` if `is_below` is `true`, and `constant < self` otherwise.
+    ///
+    /// The bits of `constant` are scanned from least to most significant, tracking whether `self` is beyond
+    /// `constant` in the lower bits. A bit where `self` can overtake `constant` is merged in with an OR,
+    /// and a bit where `self` can at best tie is merged in with an AND. Both are free while the running
+    /// result is still the constant `false`, so only the bits above the lowest deciding bit incur a constraint.
+    fn is_beyond_constant(&self, constant: &Self, is_below: bool) -> Boolean<E> {
+        self.bits_le.iter().zip_eq(&constant.bits_le).enumerate().fold(
+            Boolean::constant(false),
+            |is_beyond, (index, (bit, constant_bit))| {
+                // For signed integers, flipping the most significant bit of both operands
+                // maps the two's complement ordering onto the unsigned ordering.
+                let is_flipped = I::is_signed() && index == I::BITS as usize - 1;
+                // Determine whether `self` differs from `constant` at this bit in the direction of the comparison.
+                let is_ahead = match is_flipped != is_below {
+                    true => !bit,
+                    false => bit.clone(),
+                };
+                match (constant_bit.eject_value() != is_flipped) == is_below {
+                    true => is_ahead | is_beyond,
+                    false => is_ahead & is_beyond,
+                }
+            },
+        )
+    }
+}
+
 impl<E: Environment, I: IntegerType> Metrics<dyn Compare<Integer<E, I>, Output = Boolean<E>>> for Integer<E, I> {
     type Case = (Mode, Mode);
 
@@ -183,6 +247,132 @@ mod tests {
         }
     }
 
+    /// Checks that the comparisons against a constant agree with the generic comparisons.
+    fn check_compare_against_constant<I: IntegerType>(
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        constant: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let variable = Integer::<Circuit, I>::new(mode, value);
+        let constant = Integer::<Circuit, I>::new(Mode::Constant, constant);
+
+        assert_eq!(
+            variable.is_less_than(&constant).eject_value(),
+            variable.is_less_than_constant(&constant).eject_value()
+        );
+        assert_eq!(
+            variable.is_greater_than(&constant).eject_value(),
+            variable.is_greater_than_constant(&constant).eject_value()
+        );
+        assert_eq!(
+            variable.is_less_than_or_equal(&constant).eject_value(),
+            variable.is_less_than_or_equal_constant(&constant).eject_value()
+        );
+        assert_eq!(
+            variable.is_greater_than_or_equal(&constant).eject_value(),
+            variable.is_greater_than_or_equal_constant(&constant).eject_value()
+        );
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn run_constant_test<I: IntegerType>() {
+        let mut rng = TestRng::default();
+
+        // Select constants with runs of leading and trailing zeros and ones, as well as the sign boundaries.
+        let mut constants = vec![I::zero(), I::one(), I::MAX, I::MIN, I::zero().wrapping_sub(&I::one())];
+        constants
+            .extend((0..ITERATIONS).map(|_| *console::Integer::<<Circuit as Environment>::Network, I>::rand(&mut rng)));
+
+        for constant in &constants {
+            let neighbors = [constant.wrapping_sub(&I::one()), constant.wrapping_add(&I::one())];
+            for value in constants.iter().chain(&neighbors) {
+                for mode in [Mode::Public, Mode::Private] {
+                    check_compare_against_constant::<I>(
+                        console::Integer::new(*value),
+                        console::Integer::new(*constant),
+                        mode,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_compare_against_constant() {
+        run_constant_test::<i8>();
+        run_constant_test::<i16>();
+        run_constant_test::<i32>();
+        run_constant_test::<i64>();
+        run_constant_test::<i128>();
+
+        run_constant_test::<u8>();
+        run_constant_test::<u16>();
+        run_constant_test::<u32>();
+        run_constant_test::<u64>();
+        run_constant_test::<u128>();
+    }
+
+    #[test]
+    fn test_compare_against_constant_costs() {
+        let a = U8::<Circuit>::new(Mode::Public, console::U8::new(200));
+        let b = I8::<Circuit>::new(Mode::Public, console::I8::new(-100));
+        let constant = |value: u8| U8::<Circuit>::constant(console::U8::new(value));
+        let (zero, one, sixteen, one_twenty_eight) = (constant(0), constant(1), constant(16), constant(128));
+        let (signed_zero, signed_max) = (I8::constant(console::I8::new(0)), I8::constant(console::I8::MAX));
+
+        // The comparison is decided statically, and is free.
+        Circuit::scope("u8 < 0", || {
+            let candidate = a.is_less_than_constant(&zero);
+            assert!(candidate.is_constant() && !candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::scope("i8 > 127", || {
+            let candidate = b.is_greater_than_constant(&signed_max);
+            assert!(candidate.is_constant() && !candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+
+        // The comparison is decided by the most significant bit, and is free.
+        Circuit::scope("i8 < 0", || {
+            let candidate = b.is_less_than_constant(&signed_zero);
+            assert!(candidate.eject_value());
+            assert_eq!(Mode::Public, candidate.eject_mode());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::scope("u8 < 128", || {
+            let candidate = a.is_less_than_constant(&one_twenty_eight);
+            assert!(!candidate.eject_value());
+            assert_eq!(Mode::Private, candidate.eject_mode());
+            assert_scope!(0, 0, 0, 0);
+        });
+
+        // The comparison costs one constraint per bit above the lowest deciding bit.
+        Circuit::scope("u8 < 16", || {
+            assert!(!a.is_less_than_constant(&sixteen).eject_value());
+            assert_scope!(0, 0, 3, 3);
+        });
+        Circuit::scope("u8 > 1", || {
+            assert!(a.is_greater_than_constant(&one).eject_value());
+            assert_scope!(0, 0, 6, 6);
+        });
+
+        // The generic comparison against a constant is unchanged.
+        Circuit::scope("u8 < 16 (generic)", || {
+            assert!(!a.is_less_than(&sixteen).eject_value());
+            assert_count!(Compare(Integer<u8>, Integer<u8>) => Boolean, &(Mode::Public, Mode::Constant));
+        });
+        Circuit::reset();
+
+        // Comparing against a variable falls back to the generic comparison.
+        let c = U8::<Circuit>::new(Mode::Private, console::U8::new(16));
+        Circuit::scope("u8 < u8", || {
+            assert!(!a.is_less_than_constant(&c).eject_value());
+            assert_count!(Compare(Integer<u8>, Integer<u8>) => Boolean, &(Mode::Public, Mode::Private));
+        });
+        Circuit::reset();
+    }
+
     test_integer_binary!(run_test, i8, compare_with);
     test_integer_binary!(run_test, i16, compare_with);
     test_integer_binary!(run_test, i32, compare_with);
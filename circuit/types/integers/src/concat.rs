@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the concatenation of `lo` and `hi` as an integer of type `I`,
+    /// where the bits of `lo` form the least significant bits of the output.
+    ///
+    /// This is a concatenation of the bits, and requires no constraints.
+    /// Halts if the bit widths of `Lo` and `Hi` do not sum to the bit width of `I`.
+    pub fn concat<Lo: IntegerType, Hi: IntegerType>(lo: &Integer<E, Lo>, hi: &Integer<E, Hi>) -> Self {
+        // Ensure the bit widths of the inputs sum to the bit width of the output.
+        if Lo::BITS + Hi::BITS != I::BITS {
+            E::halt(format!(
+                "Cannot concatenate a {}-bit and a {}-bit integer into a {}-bit integer",
+                Lo::BITS,
+                Hi::BITS,
+                I::BITS
+            ))
+        }
+        Integer { bits_le: lo.bits_le.iter().chain(hi.bits_le.iter()).cloned().collect(), phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_concat<Lo: IntegerType + RefUnwindSafe, Hi: IntegerType + RefUnwindSafe, I: IntegerType>(
+        name: &str,
+        lo: console::Integer<<Circuit as Environment>::Network, Lo>,
+        hi: console::Integer<<Circuit as Environment>::Network, Hi>,
+        mode_lo: Mode,
+        mode_hi: Mode,
+    ) {
+        let a = Integer::<Circuit, Lo>::new(mode_lo, lo);
+        let b = Integer::<Circuit, Hi>::new(mode_hi, hi);
+        // Compute the expected value as `(hi << Lo::BITS) | lo`.
+        let expected = (hi.to_u128().unwrap() << Lo::BITS) | lo.to_u128().unwrap();
+        Circuit::scope(name, || {
+            let candidate = Integer::<Circuit, I>::concat(&a, &b);
+            assert_eq!(expected, candidate.eject_value().to_u128().unwrap());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<Lo: IntegerType + RefUnwindSafe, Hi: IntegerType + RefUnwindSafe, I: IntegerType>(
+        mode_lo: Mode,
+        mode_hi: Mode,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let lo = Uniform::rand(&mut rng);
+            let hi = Uniform::rand(&mut rng);
+
+            let name = format!("Concat: ({mode_lo}, {mode_hi}) {i}");
+            check_concat::<Lo, Hi, I>(&name, lo, hi, mode_lo, mode_hi);
+        }
+
+        check_concat::<Lo, Hi, I>("(0, 0)", console::Integer::zero(), console::Integer::zero(), mode_lo, mode_hi);
+        check_concat::<Lo, Hi, I>("(MAX, 0)", console::Integer::MAX, console::Integer::zero(), mode_lo, mode_hi);
+        check_concat::<Lo, Hi, I>("(0, MAX)", console::Integer::zero(), console::Integer::MAX, mode_lo, mode_hi);
+        check_concat::<Lo, Hi, I>("(MAX, MAX)", console::Integer::MAX, console::Integer::MAX, mode_lo, mode_hi);
+    }
+
+    fn run_all_modes<Lo: IntegerType + RefUnwindSafe, Hi: IntegerType + RefUnwindSafe, I: IntegerType>() {
+        for mode_lo in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_hi in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<Lo, Hi, I>(mode_lo, mode_hi);
+            }
+        }
+    }
+
+    #[test]
+    fn test_concat_u64_from_u32s() {
+        run_all_modes::<u32, u32, u64>();
+    }
+
+    #[test]
+    fn test_concat_u16_from_u8s() {
+        run_all_modes::<u8, u8, u16>();
+    }
+
+    #[test]
+    fn test_concat_u32_from_u16s() {
+        run_all_modes::<u16, u16, u32>();
+    }
+
+    #[test]
+    fn test_concat_u128_from_u64s() {
+        run_all_modes::<u64, u64, u128>();
+    }
+
+    #[test]
+    fn test_concat_mismatched_widths_halts() {
+        let a = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::zero());
+        let b = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::zero());
+        check_operation_halts(&a, &b, Integer::<Circuit, u32>::concat);
+        check_operation_halts(&a, &b, Integer::<Circuit, u8>::concat);
+        Circuit::reset();
+    }
+}
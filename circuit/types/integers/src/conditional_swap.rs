@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `(b, a)` if `condition` is `true`, otherwise returns `(a, b)`.
+    ///
+    /// This costs two `ternary` selects, and is free if `condition` is a constant.
+    pub fn conditional_swap(condition: &Boolean<E>, a: Integer<E, I>, b: Integer<E, I>) -> (Self, Self) {
+        let first = Self::ternary(condition, &b, &a);
+        let second = Self::ternary(condition, &a, &b);
+        (first, second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn run_test<I: IntegerType>(mode_condition: Mode, mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for flag in [true, false] {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let expected = if flag { (second, first) } else { (first, second) };
+
+            let condition = Boolean::<Circuit>::new(mode_condition, flag);
+            let a = Integer::<Circuit, I>::new(mode_a, first);
+            let b = Integer::new(mode_b, second);
+
+            let name = format!("ConditionalSwap({flag}): if ({mode_condition}) then swap ({mode_a}, {mode_b})");
+            Circuit::scope(name, || {
+                let (candidate_a, candidate_b) = Integer::conditional_swap(&condition, a, b);
+                assert_eq!(expected, (candidate_a.eject_value(), candidate_b.eject_value()));
+                // The cost is that of two ternary selects.
+                match mode_condition.is_constant() || (mode_a.is_constant() && mode_b.is_constant()) {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, 2 * I::BITS, 2 * I::BITS),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    test_integer_ternary!(run_test, i8, if, swap, with);
+    test_integer_ternary!(run_test, i16, if, swap, with);
+    test_integer_ternary!(run_test, i32, if, swap, with);
+    test_integer_ternary!(run_test, i64, if, swap, with);
+    test_integer_ternary!(run_test, i128, if, swap, with);
+
+    test_integer_ternary!(run_test, u8, if, swap, with);
+    test_integer_ternary!(run_test, u16, if, swap, with);
+    test_integer_ternary!(run_test, u32, if, swap, with);
+    test_integer_ternary!(run_test, u64, if, swap, with);
+    test_integer_ternary!(run_test, u128, if, swap, with);
+}
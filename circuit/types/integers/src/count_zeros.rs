@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> CountZeros for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    fn count_zeros(&self) -> Self::Output {
+        // Instead of counting the bits with integer addition, sum the negated bits in the base field.
+        // Note: This is safe as the bitwidth is less than the base field modulus.
+        let count = self.bits_le.iter().fold(Field::zero(), |sum, bit| sum + Field::from_boolean(&!bit));
+        // Decompose the sum into an unsigned 32-bit integer.
+        // Note: This is safe as the bitwidth is at most 128, which fits in an unsigned 32-bit integer.
+        U32::from_field(count)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn CountZeros<Output = U32<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(32, 0, 0, 0),
+            _ => Count::is(0, 0, 32, 33),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn CountZeros<Output = U32<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_count_zeros<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = (*value).count_zeros();
+        Circuit::scope(name, || {
+            let candidate = a.count_zeros();
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(CountZeros(Integer<I>) => Integer<u32>, &mode);
+            assert_output_mode!(CountZeros(Integer<I>) => Integer<u32>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("CountZeros: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_count_zeros::<I>(&name, value, mode);
+        }
+
+        check_count_zeros::<I>(&format!("CountZeros: {mode} zero"), console::Integer::zero(), mode);
+        check_count_zeros::<I>(&format!("CountZeros: {mode} one"), console::Integer::one(), mode);
+        check_count_zeros::<I>(&format!("CountZeros: {mode} max"), console::Integer::MAX, mode);
+        check_count_zeros::<I>(&format!("CountZeros: {mode} min"), console::Integer::MIN, mode);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("CountZeros: {mode}");
+            check_count_zeros::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, count_zeros);
+    test_integer_unary!(run_test, i16, count_zeros);
+    test_integer_unary!(run_test, i32, count_zeros);
+    test_integer_unary!(run_test, i64, count_zeros);
+    test_integer_unary!(run_test, i128, count_zeros);
+
+    test_integer_unary!(run_test, u8, count_zeros);
+    test_integer_unary!(run_test, u16, count_zeros);
+    test_integer_unary!(run_test, u32, count_zeros);
+    test_integer_unary!(run_test, u64, count_zeros);
+    test_integer_unary!(run_test, u128, count_zeros);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, count_zeros, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, count_zeros, exhaustive);
+}
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> DivCeil<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn div_ceil(&self, other: &Integer<E, I>) -> Self::Output {
+        // Compute the truncated quotient and remainder of `self` and `other`, from a single division.
+        // Note that this call halts, or fails to satisfy the circuit, on division by zero and on overflow.
+        let (quotient, remainder) = self.div_rem_checked(other);
+
+        // The truncated quotient is rounded up iff the remainder is nonzero and the exact quotient is positive.
+        // For signed integers, the exact quotient is positive if and only if the operands share a sign.
+        let is_remainder_nonzero = remainder.is_not_equal(&Integer::zero());
+        let is_rounded_up = match I::is_signed() {
+            true => is_remainder_nonzero & self.msb().is_equal(other.msb()),
+            false => is_remainder_nonzero,
+        };
+
+        // Add one to the quotient if it is rounded up.
+        // Note: This addition cannot overflow, as the quotient is only rounded up if `|other| > 1`.
+        quotient.add_wrapped(&Integer::from_bits_le(&[is_rounded_up]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the ceiling of `first / second`, or `None` on division by zero or overflow.
+    fn div_ceil<I: IntegerType>(first: I, second: I) -> Option<I> {
+        let (quotient, remainder) = (first.checked_div(&second)?, first.checked_rem(&second)?);
+        match (remainder > I::zero() && second > I::zero()) || (remainder < I::zero() && second < I::zero()) {
+            true => Some(quotient + I::one()),
+            false => Some(quotient),
+        }
+    }
+
+    fn check_div_ceil<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        match div_ceil(*first, *second) {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.div_ceil(&b);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_eq!(console::Integer::new(expected), candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            }),
+            None => match (mode_a, mode_b) {
+                (_, Mode::Constant) if second == console::Integer::zero() => {
+                    check_operation_halts(&a, &b, Integer::div_ceil)
+                }
+                (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::div_ceil),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.div_ceil(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("DivCeil: {mode_a} / {mode_b} {i}");
+            check_div_ceil::<I>(&name, first, second, mode_a, mode_b);
+            check_div_ceil::<I>(&name, second, first, mode_a, mode_b);
+
+            // Check the case where the remainder is zero.
+            let name = format!("DivCeil Exact: {mode_a} / {mode_b} {i}");
+            if let Some(product) = (*first).checked_mul(&*second) {
+                check_div_ceil::<I>(&name, console::Integer::new(product), second, mode_a, mode_b);
+            }
+
+            let name = format!("DivCeil by Zero: {mode_a} / {mode_b} {i}");
+            check_div_ceil::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+        }
+
+        // Check standard properties and corner cases.
+        let two = console::Integer::one() + console::Integer::one();
+        let three = two + console::Integer::one();
+        let seven = three + three + console::Integer::one();
+        check_div_ceil::<I>("7 / 3", seven, three, mode_a, mode_b);
+        check_div_ceil::<I>("6 / 3", three + three, three, mode_a, mode_b);
+        check_div_ceil::<I>("MAX / 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_div_ceil::<I>("MAX / 2", console::Integer::MAX, two, mode_a, mode_b);
+        check_div_ceil::<I>("MAX / MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_div_ceil::<I>("MIN / MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        check_div_ceil::<I>("MAX / 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+
+        // Check the signed corner cases.
+        if I::is_signed() {
+            check_div_ceil::<I>("-7 / 3", -seven, three, mode_a, mode_b);
+            check_div_ceil::<I>("7 / -3", seven, -three, mode_a, mode_b);
+            check_div_ceil::<I>("-7 / -3", -seven, -three, mode_a, mode_b);
+            check_div_ceil::<I>("MIN / 2", console::Integer::MIN, two, mode_a, mode_b);
+            check_div_ceil::<I>("MIN / -2", console::Integer::MIN, -two, mode_a, mode_b);
+            check_div_ceil::<I>("MIN / -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_div_ceil::<I>("MAX / -2", console::Integer::MAX, -two, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("DivCeil: ({first} / {second})");
+                check_div_ceil::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    fn check_single_division<I: IntegerType>() {
+        let a = Integer::<Circuit, I>::new(Mode::Private, console::Integer::MAX);
+        let b = Integer::<Circuit, I>::new(Mode::Private, console::Integer::one() + console::Integer::one());
+
+        // Ensure the quotient and remainder are derived from a single division gadget,
+        // which is cheaper than computing `div_checked` and `rem_checked` separately.
+        let num_constraints = Circuit::scope("DivCeil", || {
+            let _candidate = a.div_ceil(&b);
+            Circuit::num_constraints_in_scope()
+        });
+        let num_separate_constraints = Circuit::scope("DivChecked and RemChecked", || {
+            let _quotient = a.div_checked(&b);
+            let _remainder = a.rem_checked(&b);
+            Circuit::num_constraints_in_scope()
+        });
+        assert!(num_constraints < num_separate_constraints, "{num_constraints} >= {num_separate_constraints}");
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_div_ceil_single_division() {
+        check_single_division::<i8>();
+        check_single_division::<i32>();
+        check_single_division::<i128>();
+        check_single_division::<u8>();
+        check_single_division::<u32>();
+        check_single_division::<u128>();
+    }
+
+    test_integer_binary!(run_test, i8, div_ceil);
+    test_integer_binary!(run_test, i16, div_ceil);
+    test_integer_binary!(run_test, i32, div_ceil);
+    test_integer_binary!(run_test, i64, div_ceil);
+    test_integer_binary!(run_test, i128, div_ceil);
+
+    test_integer_binary!(run_test, u8, div_ceil);
+    test_integer_binary!(run_test, u16, div_ceil);
+    test_integer_binary!(run_test, u32, div_ceil);
+    test_integer_binary!(run_test, u64, div_ceil);
+    test_integer_binary!(run_test, u128, div_ceil);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, div_ceil, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, div_ceil, exhaustive);
+}
@@ -108,6 +108,69 @@ impl<E: Environment, I: IntegerType> DivChecked<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the truncated quotient and remainder of `self` and `other`, from a single division gadget.
+    /// This halts (or is unsatisfiable) on division by zero and on overflow, in the same manner as `div_checked`.
+    pub(super) fn div_rem_checked(&self, other: &Integer<E, I>) -> (Self, Self) {
+        match (self.is_constant(), other.is_constant()) {
+            // If `other` is a constant and is zero, then halt.
+            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            // If `self` and `other` are constants, and other is not zero, then directly return the results.
+            (true, true) => {
+                match (
+                    self.eject_value().checked_div(&other.eject_value()),
+                    self.eject_value().checked_rem(&other.eject_value()),
+                ) {
+                    (Some(quotient), Some(remainder)) => (
+                        Integer::constant(console::Integer::new(quotient)),
+                        Integer::constant(console::Integer::new(remainder)),
+                    ),
+                    _ => E::halt("Overflow on division of two integer constants"),
+                }
+            }
+            // Handle the remaining cases.
+            // Note that `other` is either a constant and non-zero, or not a constant.
+            _ => {
+                if I::is_signed() {
+                    // Ensure that overflow cannot occur in this division.
+                    // Signed integer division wraps when the dividend is Integer::MIN and the divisor is -1.
+                    let min = Integer::constant(console::Integer::MIN);
+                    let neg_one = Integer::constant(-console::Integer::one());
+                    let overflows = self.is_equal(&min) & other.is_equal(&neg_one);
+                    E::assert(!overflows);
+
+                    // Divide the absolute value of `self` and `other` in the base field.
+                    // Note that `abs_wrapped` is safe, as the case for console::Integer::MIN is handled above.
+                    let unsigned_dividend = self.abs_wrapped().cast_as_dual();
+                    // Note that `unsigned_divisor` is zero iff `other` is zero.
+                    let unsigned_divisor = other.abs_wrapped().cast_as_dual();
+                    // Note that this call checks that `unsigned_divisor` is not zero.
+                    let (unsigned_quotient, unsigned_remainder) =
+                        unsigned_dividend.unsigned_division_via_witness(&unsigned_divisor);
+
+                    let signed_quotient = Integer { bits_le: unsigned_quotient.bits_le, phantom: Default::default() };
+                    let signed_remainder = Integer { bits_le: unsigned_remainder.bits_le, phantom: Default::default() };
+                    let operands_same_sign = &self.msb().is_equal(other.msb());
+
+                    // The quotient is negative iff the operands differ in sign, and the remainder takes on
+                    // the same sign as `self`, because the division operation rounds towards zero.
+                    let quotient = Self::ternary(
+                        operands_same_sign,
+                        &signed_quotient,
+                        &Self::zero().sub_wrapped(&signed_quotient),
+                    );
+                    let remainder =
+                        Self::ternary(&!self.msb(), &signed_remainder, &Self::zero().sub_wrapped(&signed_remainder));
+                    (quotient, remainder)
+                } else {
+                    // Note that this call checks that `other` is not zero.
+                    self.unsigned_division_via_witness(other)
+                }
+            }
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Metrics<dyn Div<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
     type Case = (Mode, Mode);
 
@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> DivEuclidean<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn div_euclidean(&self, other: &Integer<E, I>) -> Self::Output {
+        match I::is_signed() {
+            true => {
+                // Compute the truncated quotient and remainder of `self` and `other`, from a single division.
+                // Note that this call halts, or fails to satisfy the circuit, on division by zero and on overflow.
+                let (quotient, remainder) = self.div_rem_checked(other);
+
+                // The truncated remainder is negative if and only if `self` is negative and not divisible by `other`.
+                // In this case, the quotient is rounded away from zero, towards the opposite sign of `other`.
+                let one = Self::one();
+                let adjusted_quotient =
+                    Self::ternary(other.msb(), &quotient.add_wrapped(&one), &quotient.sub_wrapped(&one));
+
+                Self::ternary(remainder.msb(), &adjusted_quotient, &quotient)
+            }
+            // For unsigned integers, Euclidean division is equivalent to truncated division.
+            false => self.div_checked(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the Euclidean quotient and remainder of `first` and `second`, or `None` on division by zero or overflow.
+    fn div_rem_euclid<I: IntegerType>(first: I, second: I) -> Option<(I, I)> {
+        let (quotient, remainder) = (first.checked_div(&second)?, first.checked_rem(&second)?);
+        match remainder < I::zero() {
+            true if second < I::zero() => Some((quotient + I::one(), remainder - second)),
+            true => Some((quotient - I::one(), remainder + second)),
+            false => Some((quotient, remainder)),
+        }
+    }
+
+    fn check_div_euclidean<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        match div_rem_euclid(*first, *second) {
+            Some((expected, _)) => Circuit::scope(name, || {
+                let candidate = a.div_euclidean(&b);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_eq!(console::Integer::new(expected), candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            }),
+            None => match (mode_a, mode_b) {
+                (_, Mode::Constant) if second == console::Integer::zero() => {
+                    check_operation_halts(&a, &b, Integer::div_euclidean)
+                }
+                (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::div_euclidean),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.div_euclidean(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn check_identity<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        Circuit::scope(name, || {
+            let quotient = a.div_euclidean(&b);
+            let remainder = a.rem_euclidean(&b);
+
+            // Check that `a == div_euclidean(a, b) * b + rem_euclidean(a, b)`.
+            assert_eq!(first, quotient.mul_wrapped(&b).add_wrapped(&remainder).eject_value());
+            // Check that `0 <= rem_euclidean(a, b) < |b|`.
+            let remainder = *remainder.eject_value();
+            assert!(remainder >= I::zero());
+            match *second < I::zero() {
+                // Note: `remainder + second` cannot overflow, as the operands have different signs.
+                true => assert!(remainder + *second < I::zero()),
+                false => assert!(remainder < *second),
+            }
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("DivEuclidean: {mode_a} / {mode_b} {i}");
+            check_div_euclidean::<I>(&name, first, second, mode_a, mode_b);
+            check_div_euclidean::<I>(&name, second, first, mode_a, mode_b);
+
+            let name = format!("DivEuclidean by Zero: {mode_a} / {mode_b} {i}");
+            check_div_euclidean::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+
+            // Check the Euclidean identity, provided that the division is well-defined.
+            if div_rem_euclid(*first, *second).is_some() {
+                let name = format!("DivEuclidean Identity: {mode_a} / {mode_b} {i}");
+                check_identity::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+
+        // Check standard properties and corner cases.
+        check_div_euclidean::<I>("MAX / 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_div_euclidean::<I>("MIN / 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_div_euclidean::<I>("0 / MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        check_div_euclidean::<I>("MIN / MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        check_div_euclidean::<I>("MAX / 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+
+        // Check the signed corner cases.
+        if I::is_signed() {
+            let three = console::Integer::one() + console::Integer::one() + console::Integer::one();
+            let seven = three + three + console::Integer::one();
+            check_div_euclidean::<I>("-7 / 3", -seven, three, mode_a, mode_b);
+            check_div_euclidean::<I>("-7 / -3", -seven, -three, mode_a, mode_b);
+            check_div_euclidean::<I>("7 / -3", seven, -three, mode_a, mode_b);
+            check_div_euclidean::<I>("MIN / -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_div_euclidean::<I>("MIN / MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+            check_identity::<I>("-7 / 3", -seven, three, mode_a, mode_b);
+            check_identity::<I>("-7 / -3", -seven, -three, mode_a, mode_b);
+            check_identity::<I>("MAX / MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+            check_identity::<I>("-1 / MIN", -console::Integer::one(), console::Integer::MIN, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("DivEuclidean: ({first} / {second})");
+                check_div_euclidean::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    fn check_single_division<I: IntegerType>() {
+        let a = Integer::<Circuit, I>::new(Mode::Private, console::Integer::MAX);
+        let b = Integer::<Circuit, I>::new(Mode::Private, console::Integer::one() + console::Integer::one());
+
+        // Ensure the quotient and remainder are derived from a single division gadget,
+        // which is cheaper than computing `div_checked` and `rem_checked` separately.
+        let num_constraints = Circuit::scope("DivEuclidean", || {
+            let _candidate = a.div_euclidean(&b);
+            Circuit::num_constraints_in_scope()
+        });
+        let num_separate_constraints = Circuit::scope("DivChecked and RemChecked", || {
+            let _quotient = a.div_checked(&b);
+            let _remainder = a.rem_checked(&b);
+            Circuit::num_constraints_in_scope()
+        });
+        assert!(num_constraints < num_separate_constraints, "{num_constraints} >= {num_separate_constraints}");
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_div_euclidean_single_division() {
+        check_single_division::<i8>();
+        check_single_division::<i32>();
+        check_single_division::<i128>();
+        check_single_division::<u8>();
+        check_single_division::<u32>();
+        check_single_division::<u128>();
+    }
+
+    test_integer_binary!(run_test, i8, div_euclidean);
+    test_integer_binary!(run_test, i16, div_euclidean);
+    test_integer_binary!(run_test, i32, div_euclidean);
+    test_integer_binary!(run_test, i64, div_euclidean);
+    test_integer_binary!(run_test, i128, div_euclidean);
+
+    test_integer_binary!(run_test, u8, div_euclidean);
+    test_integer_binary!(run_test, u16, div_euclidean);
+    test_integer_binary!(run_test, u32, div_euclidean);
+    test_integer_binary!(run_test, u64, div_euclidean);
+    test_integer_binary!(run_test, u128, div_euclidean);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, div_euclidean, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, div_euclidean, exhaustive);
+}
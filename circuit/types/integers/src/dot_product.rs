@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the dot product of `a` and `b`, i.e. `sum(a[i] * b[i])`, or zero if the slices are empty.
+    ///
+    /// The products and the running sum are computed with checked arithmetic, so this halts (or is unsatisfiable)
+    /// if any intermediate product or partial sum overflows.
+    /// Note that accumulating in a wider type would only avoid halting on a transient overflow of a partial sum
+    /// (e.g. for signed terms of mixed signs), as the final result must still fit in `I`; this is not done here.
+    ///
+    /// Halts if `a` and `b` have different lengths.
+    pub fn dot_product(a: &[Self], b: &[Self]) -> Self {
+        // Ensure the slices have the same length.
+        if a.len() != b.len() {
+            E::halt(format!("Cannot compute the dot product of slices with lengths {} and {}", a.len(), b.len()))
+        }
+        a.iter().zip_eq(b.iter()).fold(Self::zero(), |sum, (a, b)| sum.add_checked(&a.mul_checked(b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const MAX_LENGTH: usize = 8;
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sample operands small enough that the dot product cannot overflow.
+        let (min, max) = match I::is_signed() {
+            true => (I::from(-3).unwrap(), I::from(3).unwrap()),
+            false => (I::zero(), I::from(3).unwrap()),
+        };
+
+        for length in 0..=MAX_LENGTH {
+            let a: Vec<_> =
+                (0..length).map(|_| Integer::<Circuit, I>::rand_bounded(mode_a, &mut rng, min, max)).collect();
+            let b: Vec<_> =
+                (0..length).map(|_| Integer::<Circuit, I>::rand_bounded(mode_b, &mut rng, min, max)).collect();
+
+            // Compute the expected dot product over the primitives.
+            let expected =
+                a.iter().zip(b.iter()).fold(I::zero(), |sum, (a, b)| sum + *a.eject_value() * *b.eject_value());
+
+            Circuit::scope(format!("DotProduct: ({mode_a}, {mode_b}) {length}"), || {
+                let candidate = Integer::dot_product(&a, &b);
+                assert_eq!(expected, *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+
+        // Ensure an overflowing dot product of constants halts.
+        let a = [Integer::<Circuit, I>::constant(console::Integer::MAX), Integer::constant(console::Integer::one())];
+        let b = [Integer::<Circuit, I>::constant(console::Integer::one()), Integer::constant(console::Integer::one())];
+        check_operation_halts(&a[..], &b[..], Integer::dot_product);
+
+        // Ensure slices of different lengths halt.
+        check_operation_halts(&a[..], &b[..1], Integer::dot_product);
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, i8, dot_product);
+    test_integer_binary!(run_test, i16, dot_product);
+    test_integer_binary!(run_test, i32, dot_product);
+    test_integer_binary!(run_test, i64, dot_product);
+    test_integer_binary!(run_test, i128, dot_product);
+
+    test_integer_binary!(run_test, u8, dot_product);
+    test_integer_binary!(run_test, u16, dot_product);
+    test_integer_binary!(run_test, u32, dot_product);
+    test_integer_binary!(run_test, u64, dot_product);
+    test_integer_binary!(run_test, u128, dot_product);
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Enforces that `self` has exactly `weight` bits set.
+    pub fn enforce_weight(&self, weight: u32) {
+        // Ensure the weight does not exceed the bitwidth.
+        if weight as u64 > I::BITS {
+            E::halt(format!("The weight ({weight}) exceeds the bitwidth ({})", I::BITS))
+        }
+
+        // Instead of counting the bits with integer addition, sum the bits in the base field.
+        // Note: This is safe as the bitwidth is less than the base field modulus.
+        let count = self.bits_le.iter().fold(Field::zero(), |sum, bit| sum + Field::from_boolean(bit));
+
+        // Ensure the number of set bits is equal to the weight.
+        E::assert_eq(count, Field::<E>::constant(console::Field::from_u32(weight)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope_fails, Circuit};
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_enforce_weight<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+        rng: &mut TestRng,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let weight = value.count_ones();
+
+        // Check that the correct weight is satisfied.
+        Circuit::scope(name, || {
+            a.enforce_weight(weight);
+            match mode {
+                Mode::Constant => assert_scope!(1, 0, 0, 0),
+                _ => assert_scope!(1, 0, 0, 1),
+            }
+        });
+        Circuit::reset();
+
+        // Check that an incorrect weight is not satisfied.
+        let wrong_weight = (weight + 1 + u32::rand(rng) % I::BITS as u32) % (I::BITS as u32 + 1);
+        match mode {
+            Mode::Constant => check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.enforce_weight(wrong_weight)),
+            _ => Circuit::scope(name, || {
+                a.enforce_weight(wrong_weight);
+                assert_scope_fails!(1, 0, 0, 1);
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+
+            let name = format!("EnforceWeight: {mode} {i}");
+            check_enforce_weight::<I>(&name, value, mode, &mut rng);
+        }
+
+        // Check the zero and bounds cases.
+        for value in [console::Integer::zero(), console::Integer::MIN, console::Integer::MAX] {
+            let name = format!("EnforceWeight: {mode} {value}");
+            check_enforce_weight::<I>(&name, value, mode, &mut rng);
+        }
+
+        // Check that a weight exceeding the bitwidth halts.
+        let a = Integer::<Circuit, I>::new(mode, Uniform::rand(&mut rng));
+        check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.enforce_weight(I::BITS as u32 + 1));
+    }
+
+    test_integer_unary!(run_test, i8, enforce_weight);
+    test_integer_unary!(run_test, i16, enforce_weight);
+    test_integer_unary!(run_test, i32, enforce_weight);
+    test_integer_unary!(run_test, i64, enforce_weight);
+    test_integer_unary!(run_test, i128, enforce_weight);
+
+    test_integer_unary!(run_test, u8, enforce_weight);
+    test_integer_unary!(run_test, u16, enforce_weight);
+    test_integer_unary!(run_test, u32, enforce_weight);
+    test_integer_unary!(run_test, u64, enforce_weight);
+    test_integer_unary!(run_test, u128, enforce_weight);
+}
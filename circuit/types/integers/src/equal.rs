@@ -33,6 +33,10 @@ impl<E: Environment, I: IntegerType> Equal<Self> for Integer<E, I> {
                 // Instead of comparing the bits of `self` and `other` directly, the integers are
                 // converted into a field elements, and checked if they are equivalent as field elements.
                 // Note: This is safe as the field is larger than the maximum integer type supported.
+                //
+                // Note: This path is also used when one operand is constant. Matching the variable against
+                // the known bits of the constant would AND together `I::BITS` literals, at a cost of
+                // `I::BITS - 1` constraints, whereas the field comparison costs 2 constraints.
                 self.to_field().is_equal(&other.to_field())
             }
         }
@@ -127,6 +131,53 @@ mod tests {
         }
     }
 
+    /// Checks that comparing against a constant costs the same as the generic path, and agrees with it.
+    fn check_equals_against_constant<I: IntegerType>(
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        constant: console::Integer<<Circuit as Environment>::Network, I>,
+    ) {
+        let variable = Integer::<Circuit, I>::new(Mode::Private, value);
+        let generic = Integer::<Circuit, I>::new(Mode::Private, constant);
+        let constant = Integer::<Circuit, I>::new(Mode::Constant, constant);
+
+        let expected = variable.is_equal(&generic).eject_value();
+        Circuit::scope("Equals Constant", || {
+            let candidate = variable.is_equal(&constant);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(value == constant.eject_value(), candidate.eject_value());
+            assert_scope!(0, 0, 2, 2);
+        });
+        Circuit::reset();
+    }
+
+    fn run_constant_test<I: IntegerType>() {
+        let mut rng = TestRng::default();
+
+        let mut constants = vec![I::zero(), I::one(), I::MAX, I::MIN, I::zero().wrapping_sub(&I::one())];
+        constants.extend((0..ITERATIONS).map(|_| -> I { Uniform::rand(&mut rng) }));
+
+        for constant in &constants {
+            for value in [*constant, constant.wrapping_add(&I::one()), Uniform::rand(&mut rng)] {
+                check_equals_against_constant::<I>(console::Integer::new(value), console::Integer::new(*constant));
+            }
+        }
+    }
+
+    #[test]
+    fn test_equals_against_constant() {
+        run_constant_test::<i8>();
+        run_constant_test::<i16>();
+        run_constant_test::<i32>();
+        run_constant_test::<i64>();
+        run_constant_test::<i128>();
+
+        run_constant_test::<u8>();
+        run_constant_test::<u16>();
+        run_constant_test::<u32>();
+        run_constant_test::<u64>();
+        run_constant_test::<u128>();
+    }
+
     test_integer_binary!(run_test, i8, equals);
     test_integer_binary!(run_test, i16, equals);
     test_integer_binary!(run_test, i32, equals);
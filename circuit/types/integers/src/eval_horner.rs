@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the evaluation of the polynomial with coefficients `coeffs` at `x`,
+    /// where `coeffs[i]` is the coefficient of `x^i`, or zero if `coeffs` is empty.
+    ///
+    /// This uses the Horner recurrence `acc = acc * x + c`, from the highest-degree coefficient downwards.
+    /// For `n` coefficients, this costs `n - 1` checked multiplications and `n - 1` checked additions,
+    /// and halts (or is unsatisfiable) if any intermediate value overflows.
+    pub fn eval_horner(coeffs: &[Self], x: &Self) -> Self {
+        let mut coeffs = coeffs.iter().rev();
+        match coeffs.next() {
+            // Initialize the accumulator with the highest-degree coefficient.
+            Some(leading) => coeffs.fold(leading.clone(), |acc, coeff| acc.mul_checked(x).add_checked(coeff)),
+            None => Self::zero(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 16;
+    const MAX_DEGREE: usize = 3;
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_coeffs: Mode, mode_x: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sample operands small enough that the evaluation cannot overflow.
+        let ((min_coeff, max_coeff), (min_x, max_x)) = match I::is_signed() {
+            true => ((I::from(-3).unwrap(), I::from(3).unwrap()), (I::from(-2).unwrap(), I::from(2).unwrap())),
+            false => ((I::zero(), I::from(3).unwrap()), (I::zero(), I::from(2).unwrap())),
+        };
+
+        for i in 0..ITERATIONS {
+            for length in 0..=MAX_DEGREE + 1 {
+                let coeffs: Vec<_> = (0..length)
+                    .map(|_| Integer::<Circuit, I>::rand_bounded(mode_coeffs, &mut rng, min_coeff, max_coeff))
+                    .collect();
+                let x = Integer::<Circuit, I>::rand_bounded(mode_x, &mut rng, min_x, max_x);
+
+                // Compute the expected evaluation as `sum(coeffs[i] * x^i)`.
+                let (expected, _) = coeffs.iter().fold((I::zero(), I::one()), |(sum, power), coeff| {
+                    (sum + *coeff.eject_value() * power, power * *x.eject_value())
+                });
+
+                Circuit::scope(format!("EvalHorner: ({mode_coeffs}, {mode_x}) {length} {i}"), || {
+                    let candidate = Integer::eval_horner(&coeffs, &x);
+                    assert_eq!(expected, *candidate.eject_value());
+                    // An empty or a single coefficient requires no constraints.
+                    // Note: The constants for an empty polynomial are the bits of `Integer::zero()`.
+                    match length {
+                        0 => assert_scope!(I::BITS, 0, 0, 0),
+                        1 => assert_scope!(0, 0, 0, 0),
+                        _ => (),
+                    }
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                });
+                Circuit::reset();
+            }
+        }
+
+        // Ensure an overflowing evaluation over constants halts.
+        let coeffs =
+            [Integer::<Circuit, I>::constant(console::Integer::one()), Integer::constant(console::Integer::MAX)];
+        let x = Integer::<Circuit, I>::constant(console::Integer::one() + console::Integer::one());
+        check_operation_halts(&coeffs[..], &x, Integer::eval_horner);
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, i8, eval_horner);
+    test_integer_binary!(run_test, i16, eval_horner);
+    test_integer_binary!(run_test, i32, eval_horner);
+    test_integer_binary!(run_test, i64, eval_horner);
+    test_integer_binary!(run_test, i128, eval_horner);
+
+    test_integer_binary!(run_test, u8, eval_horner);
+    test_integer_binary!(run_test, u16, eval_horner);
+    test_integer_binary!(run_test, u32, eval_horner);
+    test_integer_binary!(run_test, u64, eval_horner);
+    test_integer_binary!(run_test, u128, eval_horner);
+}
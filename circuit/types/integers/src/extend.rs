@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Widens `self` into an integer of type `T`, filling the new high bits with the sign bit if `I` is signed,
+    /// and with zeros otherwise. This matches the primitive `as` conversion for widening casts.
+    ///
+    /// This is a reslicing of the bits, and requires no constraints.
+    /// Halts if the bit width of `T` is less than the bit width of `I`.
+    pub fn sign_extend<T: IntegerType>(&self) -> Integer<E, T> {
+        let fill = match I::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        };
+        self.extend_with::<T>(fill)
+    }
+
+    /// Widens `self` into an integer of type `T`, filling the new high bits with zeros.
+    ///
+    /// This is a reslicing of the bits, and requires no constraints.
+    /// Halts if the bit width of `T` is less than the bit width of `I`.
+    pub fn zero_extend<T: IntegerType>(&self) -> Integer<E, T> {
+        self.extend_with::<T>(Boolean::constant(false))
+    }
+
+    /// Returns the bits of `self`, followed by copies of `fill` up to the bit width of `T`.
+    fn extend_with<T: IntegerType>(&self, fill: Boolean<E>) -> Integer<E, T> {
+        // Ensure the output type is at least as wide as the input type.
+        if T::BITS < I::BITS {
+            E::halt(format!("Cannot extend a {}-bit integer into a {}-bit integer", I::BITS, T::BITS))
+        }
+        let bits_le = self.bits_le.iter().cloned().chain(core::iter::repeat(fill)).take(T::BITS as usize).collect();
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_extend<I: IntegerType, T: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+
+        // Compute the expected values by padding the bits.
+        let bits_le = value.to_bits_le();
+        let sign = I::is_signed() && bits_le[I::BITS as usize - 1];
+        let pad = |fill: bool| {
+            let padded = bits_le.iter().copied().chain(core::iter::repeat(fill)).take(T::BITS as usize);
+            console::Integer::<_, T>::from_bits_le(&padded.collect::<Vec<_>>()).unwrap()
+        };
+
+        Circuit::scope(name, || {
+            let candidate = a.sign_extend::<T>();
+            assert_eq!(pad(sign), candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+
+            let candidate = a.zero_extend::<T>();
+            assert_eq!(pad(false), candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType, T: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("Extend: {mode} {i}");
+            check_extend::<I, T>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        check_extend::<I, T>(&format!("Extend: {mode} zero"), console::Integer::zero(), mode);
+        check_extend::<I, T>(&format!("Extend: {mode} one"), console::Integer::one(), mode);
+        check_extend::<I, T>(&format!("Extend: {mode} max"), console::Integer::MAX, mode);
+        check_extend::<I, T>(&format!("Extend: {mode} min"), console::Integer::MIN, mode);
+    }
+
+    #[test]
+    fn test_extend() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            run_test::<i8, i8>(mode);
+            run_test::<i8, i16>(mode);
+            run_test::<i8, u32>(mode);
+            run_test::<i16, i128>(mode);
+            run_test::<i32, i64>(mode);
+            run_test::<i64, u128>(mode);
+            run_test::<u8, u8>(mode);
+            run_test::<u8, i16>(mode);
+            run_test::<u16, u64>(mode);
+            run_test::<u32, i128>(mode);
+            run_test::<u64, u128>(mode);
+        }
+    }
+
+    #[test]
+    fn test_extend_matches_primitive() {
+        for value in i8::MIN..=i8::MAX {
+            let a = Integer::<Circuit, i8>::new(Mode::Private, console::Integer::new(value));
+            assert_eq!(value as i32, *a.sign_extend::<i32>().eject_value());
+            assert_eq!(value as u64, *a.sign_extend::<u64>().eject_value());
+            assert_eq!(value as u8 as i32, *a.zero_extend::<i32>().eject_value());
+            assert_eq!(value as u8 as u16, *a.zero_extend::<u16>().eject_value());
+            Circuit::reset();
+        }
+        for value in u8::MIN..=u8::MAX {
+            let a = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::new(value));
+            assert_eq!(value as i16, *a.sign_extend::<i16>().eject_value());
+            assert_eq!(value as u32, *a.zero_extend::<u32>().eject_value());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_extend_into_narrower_halts() {
+        let a = Integer::<Circuit, u16>::new(Mode::Private, console::Integer::one());
+        check_unary_operation_halts(&a, |a| a.sign_extend::<u8>());
+        check_unary_operation_halts(&a, |a| a.zero_extend::<i8>());
+        Circuit::reset();
+    }
+}
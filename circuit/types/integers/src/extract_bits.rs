@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the `len` contiguous bits of `self` starting at bit `start`, as an integer of type `T`.
+    /// The bits are interpreted in little-endian order, so bit `start` becomes the least significant bit.
+    ///
+    /// This is a reslicing of the bits, and requires no constraints.
+    /// Halts if `len` is not the bit width of `T`, or if the range exceeds the bit width of `I`.
+    pub fn extract_bits<T: IntegerType>(&self, start: usize, len: usize) -> Integer<E, T> {
+        // Ensure the length matches the bit width of the output type.
+        if len as u64 != T::BITS {
+            E::halt(format!("Cannot extract {len} bits into a {}-bit integer", T::BITS))
+        }
+        // Ensure the range is within the bit width of `self`.
+        match start.checked_add(len) {
+            Some(end) if end <= self.bits_le.len() => {
+                Integer { bits_le: self.bits_le[start..end].to_vec(), phantom: Default::default() }
+            }
+            _ => E::halt(format!("Cannot extract bits {start}..{start}+{len} from a {}-bit integer", I::BITS)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_extract_bits<I: IntegerType + RefUnwindSafe, T: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        start: usize,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Compute the expected value by shifting and truncating the bits.
+        let expected =
+            console::Integer::<_, T>::from_bits_le(&value.to_bits_le()[start..start + T::BITS as usize]).unwrap();
+        Circuit::scope(name, || {
+            let candidate = a.extract_bits::<T>(start, T::BITS as usize);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(mode, candidate.eject_mode());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, T: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            // Extract at every offset, including those that are not byte-aligned (e.g. at nibble boundaries).
+            for start in 0..=(I::BITS - T::BITS) as usize {
+                let name = format!("ExtractBits: {mode} {start} {i}");
+                check_extract_bits::<I, T>(&name, value, start, mode);
+            }
+        }
+
+        // Ensure a mismatched length halts.
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::zero());
+        check_unary_operation_halts(&a, |a| a.extract_bits::<T>(0, T::BITS as usize + 1));
+        // Ensure an out-of-range slice halts.
+        let start = (I::BITS - T::BITS) as usize + 1;
+        check_unary_operation_halts(&a, |a| a.extract_bits::<T>(start, T::BITS as usize));
+        check_unary_operation_halts(&a, |a| a.extract_bits::<T>(usize::MAX, T::BITS as usize));
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_extract_bits_from_u32() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            run_test::<u32, u8>(mode);
+            run_test::<u32, i8>(mode);
+            run_test::<u32, u16>(mode);
+            run_test::<u32, u32>(mode);
+        }
+    }
+
+    #[test]
+    fn test_extract_bits_from_u64() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            run_test::<u64, u8>(mode);
+            run_test::<u64, u16>(mode);
+            run_test::<u64, i32>(mode);
+            run_test::<u64, u32>(mode);
+        }
+    }
+
+    #[test]
+    fn test_extract_bytes() {
+        let value = console::Integer::<<Circuit as Environment>::Network, u64>::new(0x0123_4567_89ab_cdef);
+        let a = Integer::<Circuit, u64>::new(Mode::Private, value);
+        // Extract each byte and nibble-offset byte, and check them against the expected values.
+        for (start, expected) in [(0, 0xefu8), (4, 0xde), (8, 0xcd), (28, 0x78), (56, 0x01)] {
+            assert_eq!(expected, *a.extract_bits::<u8>(start, 8).eject_value());
+        }
+    }
+}
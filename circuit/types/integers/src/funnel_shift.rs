@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the lower `I::BITS` bits of the `2 * I::BITS`-bit concatenation `hi:lo`, shifted right by `shift`.
+    /// For `shift <= I::BITS`, this is equivalent to the logical shift `(lo >> shift) | (hi << (I::BITS - shift))`.
+    /// The vacated bits are filled with zeros, so a shift of at least `2 * I::BITS` returns zero.
+    ///
+    /// As the shift amount is constant, this is a reindexing of the bits, and requires no constraints.
+    pub fn funnel_shr(hi: &Self, lo: &Self, shift: u32) -> Self {
+        // Note: The shift amount is capped to avoid iterating over the (unbounded) zero bits.
+        let shift = core::cmp::min(shift as usize, 2 * I::BITS as usize);
+        let bits_le = lo
+            .bits_le
+            .iter()
+            .chain(hi.bits_le.iter())
+            .cloned()
+            .chain(core::iter::repeat(Boolean::constant(false)))
+            .skip(shift)
+            .take(I::BITS as usize)
+            .collect();
+        Integer { bits_le, phantom: Default::default() }
+    }
+
+    /// Returns the upper `I::BITS` bits of the `2 * I::BITS`-bit concatenation `hi:lo`, shifted left by `shift`.
+    /// For `shift <= I::BITS`, this is equivalent to the logical shift `(hi << shift) | (lo >> (I::BITS - shift))`.
+    /// The vacated bits are filled with zeros, so a shift of at least `2 * I::BITS` returns zero.
+    ///
+    /// As the shift amount is constant, this is a reindexing of the bits, and requires no constraints.
+    pub fn funnel_shl(hi: &Self, lo: &Self, shift: u32) -> Self {
+        // Note: The shift amount is capped to avoid prepending an excessive number of zero bits.
+        let shift = core::cmp::min(shift as usize, 2 * I::BITS as usize);
+        let bits_le = core::iter::repeat(Boolean::constant(false))
+            .take(shift)
+            .chain(lo.bits_le.iter().chain(hi.bits_le.iter()).cloned())
+            .skip(I::BITS as usize)
+            .take(I::BITS as usize)
+            .collect();
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    /// Returns the bits of the concatenation `hi:lo`, shifted left by `shift`, padded with zeros on both ends.
+    /// The bit at index `4 * I::BITS` of the output is the least significant bit of the unshifted `lo`.
+    fn padded_bits<I: IntegerType>(
+        hi: console::Integer<<Circuit as Environment>::Network, I>,
+        lo: console::Integer<<Circuit as Environment>::Network, I>,
+    ) -> Vec<bool> {
+        let padding = vec![false; 4 * I::BITS as usize];
+        padding
+            .iter()
+            .chain(lo.to_bits_le().iter())
+            .chain(hi.to_bits_le().iter())
+            .chain(padding.iter())
+            .copied()
+            .collect()
+    }
+
+    fn check_funnel_shift<I: IntegerType>(
+        name: &str,
+        hi: console::Integer<<Circuit as Environment>::Network, I>,
+        lo: console::Integer<<Circuit as Environment>::Network, I>,
+        shift: u32,
+        mode_hi: Mode,
+        mode_lo: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_hi, hi);
+        let b = Integer::<Circuit, I>::new(mode_lo, lo);
+
+        // Compute the expected values by windowing the zero-padded bits of `hi:lo`.
+        let bits = padded_bits(hi, lo);
+        let offset = 4 * I::BITS as usize;
+        let distance = core::cmp::min(shift as usize, 3 * I::BITS as usize);
+        let window =
+            |start: usize| console::Integer::<_, I>::from_bits_le(&bits[start..start + I::BITS as usize]).unwrap();
+        let expected_shr = window(offset + distance);
+        let expected_shl = window(offset + I::BITS as usize - distance);
+
+        Circuit::scope(name, || {
+            let candidate = Integer::funnel_shr(&a, &b, shift);
+            assert_eq!(expected_shr, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+
+            let candidate = Integer::funnel_shl(&a, &b, shift);
+            assert_eq!(expected_shl, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_hi: Mode, mode_lo: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let hi = Uniform::rand(&mut rng);
+            let lo = Uniform::rand(&mut rng);
+            let shift = u32::rand(&mut rng) % (2 * I::BITS as u32 + 2);
+
+            let name = format!("FunnelShift: ({mode_hi}, {mode_lo}) by {shift} {i}");
+            check_funnel_shift::<I>(&name, hi, lo, shift, mode_hi, mode_lo);
+        }
+
+        // Check the boundary shift amounts.
+        for shift in [0, 1, I::BITS as u32 - 1, I::BITS as u32, 2 * I::BITS as u32 - 1, 2 * I::BITS as u32, u32::MAX] {
+            let name = format!("FunnelShift: (MAX, MIN) by {shift}");
+            check_funnel_shift::<I>(&name, console::Integer::MAX, console::Integer::MIN, shift, mode_hi, mode_lo);
+            let name = format!("FunnelShift: (MIN, MAX) by {shift}");
+            check_funnel_shift::<I>(&name, console::Integer::MIN, console::Integer::MAX, shift, mode_hi, mode_lo);
+        }
+    }
+
+    #[test]
+    fn test_funnel_shift_reference() {
+        type N = <Circuit as Environment>::Network;
+
+        // Check the funnel shifts against the equivalent shifts of the halves.
+        let hi = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::<N, u8>::new(0b1010_0101));
+        let lo = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::<N, u8>::new(0b1100_0011));
+        assert_eq!(0b0101_1100, *Integer::funnel_shr(&hi, &lo, 4).eject_value());
+        assert_eq!(0b0101_1100, *Integer::funnel_shl(&hi, &lo, 4).eject_value());
+        assert_eq!(0b1010_0101, *Integer::funnel_shr(&hi, &lo, 8).eject_value());
+        assert_eq!(0b1100_0011, *Integer::funnel_shl(&hi, &lo, 8).eject_value());
+        assert_eq!(0b0000_1010, *Integer::funnel_shr(&hi, &lo, 12).eject_value());
+        assert_eq!(0b0011_0000, *Integer::funnel_shl(&hi, &lo, 12).eject_value());
+    }
+
+    test_integer_binary!(run_test, i8, funnel_shift);
+    test_integer_binary!(run_test, i16, funnel_shift);
+    test_integer_binary!(run_test, i32, funnel_shift);
+    test_integer_binary!(run_test, i64, funnel_shift);
+    test_integer_binary!(run_test, i128, funnel_shift);
+
+    test_integer_binary!(run_test, u8, funnel_shift);
+    test_integer_binary!(run_test, u16, funnel_shift);
+    test_integer_binary!(run_test, u32, funnel_shift);
+    test_integer_binary!(run_test, u64, funnel_shift);
+    test_integer_binary!(run_test, u128, funnel_shift);
+}
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Gcd<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the greatest common divisor of `self` and `other`, where `gcd(0, n) == n`.
+    /// This operation is only defined for unsigned integers.
+    #[inline]
+    fn gcd(&self, other: &Integer<E, I>) -> Self::Output {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("The greatest common divisor is only defined for unsigned integers")
+        }
+
+        if self.is_constant() && other.is_constant() {
+            // Compute the greatest common divisor and return the new constant.
+            witness!(|self, other| console::Integer::new(gcd(*self, *other)))
+        } else {
+            // Initialize the constants `2^(I::BITS - 1)` and `2^I::BITS`.
+            let two_pow_bits_minus_one = (1..I::BITS).fold(Field::<E>::one(), |power, _| power.double());
+            let two_pow_bits = two_pow_bits_minus_one.double();
+
+            let (mut a, mut b) = (self.bits_le.clone(), other.bits_le.clone());
+            // Initialize the power of two shared by `self` and `other`.
+            let mut shared_power = Field::one();
+
+            // Compute the binary GCD, where the selections and subtractions are performed in the base field.
+            // Note: Each iteration reduces the total bit length of `a` and `b` by at least one,
+            // until one of them is zero, so `2 * I::BITS` iterations suffice for any pair of operands.
+            for _ in 0..2 * I::BITS {
+                let (a_field, b_field) = (Field::from_bits_le(&a), Field::from_bits_le(&b));
+                let (a_half, b_half) = (Field::from_bits_le(&a[1..]), Field::from_bits_le(&b[1..]));
+                let (a_is_odd, b_is_odd) = (&a[0], &b[0]);
+                let both_odd = a_is_odd & b_is_odd;
+                let both_even = !a_is_odd & !b_is_odd;
+
+                // Compute `a - b + 2^I::BITS`, whose most significant bit is set if and only if `a >= b`.
+                let difference = (&a_field - &b_field + &two_pow_bits).to_lower_bits_le(I::BITS as usize + 1);
+                let a_is_greater_or_equal = &difference[I::BITS as usize];
+                // Compute `|a - b| / 2`, which is only used when `a` and `b` are odd, and thus `a - b` is even.
+                // If `a < b`, the lower bits of the difference are `2^I::BITS - (b - a)`, giving `(b - a) / 2`.
+                let half_difference = Field::from_bits_le(&difference[1..I::BITS as usize]);
+                let half_distance = Field::ternary(
+                    a_is_greater_or_equal,
+                    &half_difference,
+                    &(&two_pow_bits_minus_one - &half_difference),
+                );
+                let minimum = Field::ternary(a_is_greater_or_equal, &b_field, &a_field);
+
+                // If both are odd, set `(a, b) = (|a - b| / 2, min(a, b))`.
+                // Otherwise, halve the even ones, tracking the power of two if both are even.
+                let next_a = Field::ternary(&both_odd, &half_distance, &Field::ternary(a_is_odd, &a_field, &a_half));
+                let next_b = Field::ternary(&both_odd, &minimum, &Field::ternary(b_is_odd, &b_field, &b_half));
+                shared_power = Field::ternary(&both_even, &shared_power.double(), &shared_power);
+
+                a = next_a.to_lower_bits_le(I::BITS as usize);
+                b = next_b.to_lower_bits_le(I::BITS as usize);
+            }
+
+            // One of `a` and `b` is now zero, and the other is the odd part of the greatest common divisor.
+            let gcd = (Field::from_bits_le(&a) + Field::from_bits_le(&b)) * shared_power;
+
+            // Return the greatest common divisor of `self` and `other`.
+            Integer { bits_le: gcd.to_lower_bits_le(I::BITS as usize), phantom: Default::default() }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Gcd<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(
+                (6 * I::BITS * I::BITS) + (3 * I::BITS),
+                0,
+                (6 * I::BITS * I::BITS) + (21 * I::BITS),
+                (6 * I::BITS * I::BITS) + (27 * I::BITS) + 1,
+            ),
+            (_, _) => {
+                Count::is(0, 0, (6 * I::BITS * I::BITS) + (21 * I::BITS), (6 * I::BITS * I::BITS) + (27 * I::BITS) + 1)
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Gcd<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+/// Returns the greatest common divisor of `a` and `b`, using the Euclidean algorithm.
+fn gcd<I: IntegerType>(mut a: I, mut b: I) -> I {
+    while b != I::zero() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 16;
+
+    fn check_gcd<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::new(mode_b, second);
+        let expected = gcd(*first, *second);
+        Circuit::scope(name, || {
+            let candidate = a.gcd(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(Gcd(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Gcd(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Gcd: {mode_a} {mode_b} {i}");
+            check_gcd::<I>(&name, first, second, mode_a, mode_b);
+            check_gcd::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+
+            // Check operands with a large shared power of two.
+            let name = format!("Gcd Shared Power: {mode_a} {mode_b} {i}");
+            let shift = I::BITS as usize / 2;
+            let (first, second) = (console::Integer::new(*first << shift), console::Integer::new(*second << shift));
+            check_gcd::<I>(&name, first, second, mode_a, mode_b);
+        }
+
+        // Check the corner cases.
+        let one = console::Integer::one();
+        let zero = console::Integer::zero();
+        check_gcd::<I>("gcd(0, 0)", zero, zero, mode_a, mode_b);
+        check_gcd::<I>("gcd(0, 1)", zero, one, mode_a, mode_b);
+        check_gcd::<I>("gcd(0, MAX)", zero, console::Integer::MAX, mode_a, mode_b);
+        check_gcd::<I>("gcd(MAX, 0)", console::Integer::MAX, zero, mode_a, mode_b);
+        check_gcd::<I>("gcd(MAX, MAX)", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_gcd::<I>("gcd(MAX, MAX - 1)", console::Integer::MAX, console::Integer::MAX - one, mode_a, mode_b);
+        check_gcd::<I>("gcd(1, MAX)", one, console::Integer::MAX, mode_a, mode_b);
+
+        // Check powers of two, including the case where one operand is zero.
+        let msb = console::Integer::new(I::one() << (I::BITS as usize - 1));
+        check_gcd::<I>("gcd(0, 2^(BITS - 1))", zero, msb, mode_a, mode_b);
+        check_gcd::<I>("gcd(2^(BITS - 1), 2^(BITS - 1))", msb, msb, mode_a, mode_b);
+        check_gcd::<I>("gcd(2^(BITS - 1), 1)", msb, one, mode_a, mode_b);
+    }
+
+    fn run_signed_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let a = Integer::<Circuit, I>::new(mode_a, console::Integer::one());
+        let b = Integer::<Circuit, I>::new(mode_b, console::Integer::one());
+        check_operation_halts(&a, &b, Integer::gcd);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("Gcd: ({first}, {second})");
+                check_gcd::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, u8, gcd);
+    test_integer_binary!(run_test, u16, gcd);
+    test_integer_binary!(run_test, u32, gcd);
+    test_integer_binary!(run_test, u64, gcd);
+    test_integer_binary!(run_test, u128, gcd);
+
+    test_integer_binary!(run_signed_test, i8, gcd_halts);
+    test_integer_binary!(run_signed_test, i64, gcd_halts);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, gcd, exhaustive);
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the reflected Gray code of `self`, i.e. `self ^ (self >> 1)`.
+    ///
+    /// The shift is logical over the bit representation, so for signed integers the most significant bit
+    /// is carried over as is, rather than replicated. This costs `I::BITS - 1` constraints.
+    pub fn to_gray(&self) -> Self {
+        // Note: The most significant bit is XORed with the zero that is shifted in, and is unchanged.
+        let lower_bits_le = self.bits_le.windows(2).map(|pair| &pair[0] ^ &pair[1]);
+        Self {
+            bits_le: lower_bits_le.chain(core::iter::once(self.msb().clone())).collect(),
+            phantom: Default::default(),
+        }
+    }
+
+    /// Returns the integer whose reflected Gray code is `self`, the inverse of `to_gray`.
+    ///
+    /// Each bit of the result is the XOR of all bits of `self` at or above its position, which is computed
+    /// as a prefix-XOR from the most significant bit. This costs `I::BITS - 1` constraints.
+    pub fn from_gray(&self) -> Self {
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        // Note: `self.bits_le` is never empty, as `I::BITS` is at least 8.
+        let mut prefix = self.bits_le[I::BITS as usize - 1].clone();
+        bits_le.push(prefix.clone());
+        for bit in self.bits_le.iter().rev().skip(1) {
+            prefix = &prefix ^ bit;
+            bits_le.push(prefix.clone());
+        }
+        bits_le.reverse();
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    /// Returns the reflected Gray code of the given value, computed over its bits.
+    fn gray_reference<I: IntegerType>(
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+    ) -> console::Integer<<Circuit as Environment>::Network, I> {
+        let bits_le = value.to_bits_le();
+        let gray_le = (0..bits_le.len()).map(|i| bits_le[i] ^ bits_le.get(i + 1).copied().unwrap_or(false));
+        console::Integer::from_bits_le(&gray_le.collect::<Vec<_>>()).unwrap()
+    }
+
+    fn check_gray_code<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = gray_reference(value);
+
+        Circuit::scope(name, || {
+            let encoded = a.to_gray();
+            assert_eq!(expected, encoded.eject_value());
+            match mode.is_constant() {
+                true => assert_scope!(0, 0, 0, 0),
+                false => assert_scope!(0, 0, I::BITS - 1, I::BITS - 1),
+            }
+
+            let decoded = encoded.from_gray();
+            assert_eq!(value, decoded.eject_value());
+            match mode.is_constant() {
+                true => assert_scope!(0, 0, 0, 0),
+                false => assert_scope!(0, 0, 2 * (I::BITS - 1), 2 * (I::BITS - 1)),
+            }
+        });
+        Circuit::reset();
+
+        // Check that decoding the value directly matches the inverse of the reference.
+        let a = Integer::<Circuit, I>::new(mode, value);
+        Circuit::scope(name, || {
+            let decoded = a.from_gray();
+            assert_eq!(value, gray_reference(decoded.eject_value()));
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("GrayCode: {mode} {i}");
+            check_gray_code::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        check_gray_code::<I>(&format!("GrayCode: {mode} zero"), console::Integer::zero(), mode);
+        check_gray_code::<I>(&format!("GrayCode: {mode} one"), console::Integer::one(), mode);
+        check_gray_code::<I>(&format!("GrayCode: {mode} max"), console::Integer::MAX, mode);
+        check_gray_code::<I>(&format!("GrayCode: {mode} min"), console::Integer::MIN, mode);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("GrayCode: {mode} {value}");
+            check_gray_code::<I>(&name, value, mode);
+        }
+    }
+
+    #[test]
+    fn test_gray_code_matches_primitive() {
+        // For unsigned integers, the logical shift coincides with the primitive shift.
+        for value in u8::MIN..=u8::MAX {
+            let expected = console::Integer::<<Circuit as Environment>::Network, u8>::new(value ^ (value >> 1));
+            let candidate = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::new(value)).to_gray();
+            assert_eq!(expected, candidate.eject_value());
+            Circuit::reset();
+        }
+    }
+
+    test_integer_unary!(run_test, i8, gray_code);
+    test_integer_unary!(run_test, i16, gray_code);
+    test_integer_unary!(run_test, i32, gray_code);
+    test_integer_unary!(run_test, i64, gray_code);
+    test_integer_unary!(run_test, i128, gray_code);
+
+    test_integer_unary!(run_test, u8, gray_code);
+    test_integer_unary!(run_test, u16, gray_code);
+    test_integer_unary!(run_test, u32, gray_code);
+    test_integer_unary!(run_test, u64, gray_code);
+    test_integer_unary!(run_test, u128, gray_code);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, gray_code, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, gray_code, exhaustive);
+}
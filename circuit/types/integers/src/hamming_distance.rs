@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> HammingDistance<Self> for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of bit positions at which `self` and `other` differ.
+    fn hamming_distance(&self, other: &Integer<E, I>) -> Self::Output {
+        // Sum the differing bits in the base field, without constructing the intermediate integer `self ^ other`.
+        // Note: This is safe as the bitwidth is less than the base field modulus.
+        let count = self
+            .bits_le
+            .iter()
+            .zip_eq(other.bits_le.iter())
+            .fold(Field::zero(), |sum, (a, b)| sum + Field::from_boolean(&(a ^ b)));
+        // Decompose the sum into an unsigned 32-bit integer.
+        // Note: This is safe as the bitwidth is at most 128, which fits in an unsigned 32-bit integer.
+        U32::from_field(count)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn HammingDistance<Integer<E, I>, Output = U32<E>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(32, 0, 0, 0),
+            // Note: The XOR of a variable bit with a constant bit is free.
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, 32, 33),
+            (_, _) => Count::is(0, 0, I::BITS + 32, I::BITS + 33),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn HammingDistance<Integer<E, I>, Output = U32<E>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_hamming_distance<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let expected = (*first ^ *second).count_ones();
+        Circuit::scope(name, || {
+            let candidate = a.hamming_distance(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(HammingDistance(Integer<I>, Integer<I>) => Integer<u32>, &(mode_a, mode_b));
+            assert_output_mode!(HammingDistance(Integer<I>, Integer<I>) => Integer<u32>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("HammingDistance: ({mode_a}, {mode_b}) {i}");
+            check_hamming_distance::<I>(&name, first, second, mode_a, mode_b);
+            check_hamming_distance::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+
+            let name = format!("HammingDistance: ({mode_a}, {mode_b}) same {i}");
+            check_hamming_distance::<I>(&name, first, first, mode_a, mode_b);
+        }
+
+        let all_ones = !console::Integer::zero();
+        check_hamming_distance::<I>("(0, 0)", console::Integer::zero(), console::Integer::zero(), mode_a, mode_b);
+        check_hamming_distance::<I>("(0, 1s)", console::Integer::zero(), all_ones, mode_a, mode_b);
+        check_hamming_distance::<I>("(MAX, MIN)", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+        check_hamming_distance::<I>("(0, MIN)", console::Integer::zero(), console::Integer::MIN, mode_a, mode_b);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("HammingDistance: ({first}, {second})");
+                check_hamming_distance::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, hamming_distance);
+    test_integer_binary!(run_test, i16, hamming_distance);
+    test_integer_binary!(run_test, i32, hamming_distance);
+    test_integer_binary!(run_test, i64, hamming_distance);
+    test_integer_binary!(run_test, i128, hamming_distance);
+
+    test_integer_binary!(run_test, u8, hamming_distance);
+    test_integer_binary!(run_test, u16, hamming_distance);
+    test_integer_binary!(run_test, u32, hamming_distance);
+    test_integer_binary!(run_test, u64, hamming_distance);
+    test_integer_binary!(run_test, u128, hamming_distance);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, hamming_distance, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, hamming_distance, exhaustive);
+}
@@ -44,6 +44,21 @@ impl<E: Environment, I: IntegerType> FromBits for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes a new integer from exactly `I::BITS` little-endian booleans.
+    ///
+    /// Unlike `from_bits_le`, this does not accept excess bits, and wraps the given booleans directly,
+    /// so it requires no constraints. The mode of the integer is the join of the modes of the booleans.
+    /// Halts if the number of booleans is not `I::BITS`.
+    pub fn from_booleans(bits_le: &[Boolean<E>]) -> Self {
+        // Ensure the number of booleans matches the bit width.
+        if bits_le.len() as u64 != I::BITS {
+            E::halt(format!("Expected {} booleans for a {}-bit integer, found {}", I::BITS, I::BITS, bits_le.len()))
+        }
+        Self { bits_le: bits_le.to_vec(), phantom: Default::default() }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -510,4 +525,67 @@ mod tests {
         type I = i128;
         check_from_bits_be::<I>(Mode::Private, 0, 0, 0, 0);
     }
+
+    fn check_from_booleans<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random integer, and construct its bits with alternating modes.
+            let expected: console::Integer<_, I> = Uniform::rand(&mut rng);
+            let modes = [mode, Mode::Constant];
+            let given_bits = expected
+                .to_bits_le()
+                .into_iter()
+                .enumerate()
+                .map(|(j, bit)| Boolean::<Circuit>::new(modes[j % 2], bit))
+                .collect::<Vec<_>>();
+
+            Circuit::scope(&format!("FromBooleans {mode} {i}"), || {
+                let candidate = Integer::<Circuit, I>::from_booleans(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                // The mode is the join of the bit modes.
+                assert_eq!(mode, candidate.eject_mode());
+                assert_scope!(0, 0, 0, 0);
+            });
+        }
+
+        // Ensure the booleans are used directly, rather than being re-witnessed.
+        let given_bits = Integer::<Circuit, I>::new(mode, console::Integer::MAX).to_bits_le();
+        let candidate = Integer::<Circuit, I>::from_booleans(&given_bits);
+        assert_eq!(console::Integer::MAX, candidate.eject_value());
+        assert_eq!(given_bits.len(), candidate.bits_le.len());
+
+        // Ensure a mismatched number of booleans halts.
+        let too_few = &given_bits[1..];
+        let result = std::panic::catch_unwind(|| Integer::<Circuit, I>::from_booleans(too_few));
+        assert!(result.is_err());
+        let too_many = [given_bits.clone(), vec![Boolean::constant(false)]].concat();
+        let result = std::panic::catch_unwind(|| Integer::<Circuit, I>::from_booleans(&too_many));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_booleans() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_from_booleans::<u8>(mode);
+            check_from_booleans::<i8>(mode);
+            check_from_booleans::<u16>(mode);
+            check_from_booleans::<i32>(mode);
+            check_from_booleans::<u64>(mode);
+            check_from_booleans::<i128>(mode);
+        }
+    }
+
+    #[test]
+    fn test_from_booleans_known_values() {
+        let bits = |value: u8| (0..8).map(move |i| Boolean::<Circuit>::new(Mode::Private, (value >> i) & 1 == 1));
+        for value in [0x00u8, 0x01, 0x5a, 0x80, 0xff] {
+            let candidate = U8::<Circuit>::from_booleans(&bits(value).collect::<Vec<_>>());
+            assert_eq!(value, *candidate.eject_value());
+        }
+        let candidate = I8::<Circuit>::from_booleans(&bits(0xff).collect::<Vec<_>>());
+        assert_eq!(-1i8, *candidate.eject_value());
+        Circuit::reset();
+    }
 }
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> FromBytes for Integer<E, I> {
+    type Byte = U8<E>;
+
+    /// Initializes a new integer from a list of little-endian bytes *with* trailing zeros.
+    fn from_bytes_le(bytes_le: &[Self::Byte]) -> Self {
+        // Concatenate the bits of each byte.
+        let bits_le = bytes_le.iter().flat_map(|byte| byte.bits_le.iter().cloned()).collect::<Vec<_>>();
+        // Note: This checks that any excess bits are zero, and resizes up if necessary.
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_from_bytes_le<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        // Construct the bytes of the value.
+        let bytes_le = (*value)
+            .to_bytes_le()
+            .unwrap()
+            .into_iter()
+            .map(|byte| U8::<Circuit>::new(mode, console::Integer::new(byte)))
+            .collect::<Vec<_>>();
+        Circuit::scope(name, || {
+            let candidate = Integer::<Circuit, I>::from_bytes_le(&bytes_le);
+            assert_eq!(value, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+
+        // Check that the bytes round trip.
+        let candidate = Integer::<Circuit, I>::new(mode, value);
+        Circuit::scope(name, || {
+            let candidate = Integer::<Circuit, I>::from_bytes_le(&candidate.to_bytes_le());
+            assert_eq!(value, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("FromBytes: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_from_bytes_le::<I>(&name, value, mode);
+        }
+
+        // Check the bounds of the integer type.
+        for value in [console::Integer::zero(), console::Integer::one(), console::Integer::MAX, console::Integer::MIN] {
+            let name = format!("FromBytes: {mode} {value}");
+            check_from_bytes_le::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, from_bytes_le);
+    test_integer_unary!(run_test, i16, from_bytes_le);
+    test_integer_unary!(run_test, i32, from_bytes_le);
+    test_integer_unary!(run_test, i64, from_bytes_le);
+    test_integer_unary!(run_test, i128, from_bytes_le);
+
+    test_integer_unary!(run_test, u8, from_bytes_le);
+    test_integer_unary!(run_test, u16, from_bytes_le);
+    test_integer_unary!(run_test, u32, from_bytes_le);
+    test_integer_unary!(run_test, u64, from_bytes_le);
+    test_integer_unary!(run_test, u128, from_bytes_le);
+}
@@ -22,6 +22,9 @@ impl<E: Environment, I: IntegerType> FromField for Integer<E, I> {
     /// This method guarantees the following:
     ///   1. If the field element is larger than the integer domain, then the operation will fail.
     ///   2. If the field element is smaller than the integer domain, then the operation will succeed.
+    ///
+    /// For signed integers, the field element is interpreted as the two's complement representation
+    /// of the integer, i.e. `-1i8` is recovered from the field element `255`, as produced by `to_field`.
     fn from_field(field: Self::Field) -> Self {
         // Note: We are reconstituting the integer from the base field.
         // This is safe as the number of bits in the integer is less than the base field modulus,
@@ -85,6 +88,37 @@ mod tests {
         }
     }
 
+    fn check_from_field_bounds<I: IntegerType>() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Check that the bounds of the integer domain round trip through the base field.
+            for expected in [I::zero(), I::one(), I::MAX, I::MIN, I::zero().wrapping_sub(&I::one())] {
+                let expected = console::Integer::new(expected);
+                let field = Integer::<Circuit, I>::new(mode, expected).to_field();
+                Circuit::scope(format!("{mode} {expected}"), || {
+                    let candidate = Integer::<Circuit, I>::from_field(field);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_scope!();
+                });
+                Circuit::reset();
+            }
+
+            // Check that the smallest field element exceeding the integer domain, `2^I::BITS`, fails.
+            let all_ones = console::Integer::<_, I>::new(I::zero().wrapping_sub(&I::one()));
+            let field = Integer::<Circuit, I>::new(mode, all_ones).to_field() + Field::one();
+            match mode {
+                Mode::Constant => {
+                    let result = std::panic::catch_unwind(|| Integer::<Circuit, I>::from_field(field));
+                    assert!(result.is_err());
+                }
+                _ => {
+                    let _candidate = Integer::<Circuit, I>::from_field(field);
+                    assert!(!Circuit::is_satisfied());
+                }
+            }
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_u8_from_field() {
         let mut rng = TestRng::default();
@@ -93,6 +127,7 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 
     #[test]
@@ -103,6 +138,7 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 
     #[test]
@@ -113,6 +149,7 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 
     #[test]
@@ -123,6 +160,7 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 
     #[test]
@@ -133,6 +171,7 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 
     #[test]
@@ -143,6 +182,7 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 
     #[test]
@@ -153,6 +193,7 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 
     #[test]
@@ -163,6 +204,7 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 
     #[test]
@@ -173,6 +215,7 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 
     #[test]
@@ -183,5 +226,6 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_bounds::<I>();
     }
 }
@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Casts an integer from a base field, with lossy truncation, and clamps it into the range `[min, max]`.
+    ///
+    /// This method is commonly-used by hash-to-integer algorithms,
+    /// where the hash output is used to derive a bounded parameter.
+    pub fn from_field_clamped(field: &Field<E>, min: I, max: I) -> Self {
+        // Ensure the range is well-formed.
+        if min > max {
+            E::halt(format!("Invalid clamp range: the minimum ({min}) exceeds the maximum ({max})"))
+        }
+
+        // Truncate the field to the size in bits of the integer.
+        let integer = Self::from_field_lossy(field);
+
+        // Clamp the integer into the range `[min, max]`.
+        let min = Self::constant(console::Integer::new(min));
+        let max = Self::constant(console::Integer::new(max));
+        let integer = Self::ternary(&integer.is_less_than(&min), &min, &integer);
+        Self::ternary(&integer.is_greater_than(&max), &max, &integer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_from_field_clamped<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample a random clamp range.
+            let (first, second): (I, I) = (Uniform::rand(rng), Uniform::rand(rng));
+            let (min, max) = if first <= second { (first, second) } else { (second, first) };
+
+            // Sample a random integer, which may be below, inside, or above the clamp range.
+            let value: I = Uniform::rand(rng);
+            let expected = if value < min {
+                min
+            } else if value > max {
+                max
+            } else {
+                value
+            };
+            let candidate = Integer::<Circuit, I>::new(mode, console::Integer::new(value)).to_field();
+
+            Circuit::scope(format!("{mode} {value} [{min}, {max}] {i}"), || {
+                let candidate = Integer::<Circuit, I>::from_field_clamped(&candidate, min, max);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_scope!();
+            });
+            Circuit::reset();
+
+            // Sample a random field, which exceeds the integer domain.
+            let candidate = Field::<Circuit>::new(mode, Uniform::rand(rng));
+            let value = *Integer::<Circuit, I>::from_field_lossy(&candidate).eject_value();
+            let expected = if value < min {
+                min
+            } else if value > max {
+                max
+            } else {
+                value
+            };
+
+            Circuit::scope(format!("{mode} {value} [{min}, {max}] lossy {i}"), || {
+                let candidate = Integer::<Circuit, I>::from_field_clamped(&candidate, min, max);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_scope!();
+            });
+            Circuit::reset();
+        }
+
+        // Check a value below, inside, and above a fixed clamp range.
+        let (min, max) = (I::one() + I::one(), I::MAX - I::one());
+        for (value, expected) in
+            [(I::zero(), min), (I::MAX - I::one() - I::one(), I::MAX - I::one() - I::one()), (I::MAX, max)]
+        {
+            let candidate = Integer::<Circuit, I>::new(mode, console::Integer::new(value)).to_field();
+            Circuit::scope(format!("{mode} {value} [{min}, {max}]"), || {
+                let candidate = Integer::<Circuit, I>::from_field_clamped(&candidate, min, max);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_scope!();
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_field_clamped_in_range<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample a random clamp range.
+            let (first, second): (I, I) = (Uniform::rand(rng), Uniform::rand(rng));
+            let (min, max) = if first <= second { (first, second) } else { (second, first) };
+
+            // Sample a random integer inside the clamp range, which must be returned unchanged.
+            let candidate = Integer::<Circuit, I>::rand_bounded(mode, rng, min, max);
+            let value = *candidate.eject_value();
+            assert!(min <= value && value <= max);
+
+            Circuit::scope(format!("{mode} {value} [{min}, {max}] in range {i}"), || {
+                let candidate = Integer::<Circuit, I>::from_field_clamped(&candidate.to_field(), min, max);
+                assert_eq!(value, *candidate.eject_value());
+                assert_scope!();
+            });
+            Circuit::reset();
+        }
+
+        // Check that a singleton range always samples its only element.
+        let value = Uniform::rand(rng);
+        let candidate = Integer::<Circuit, I>::rand_bounded(mode, rng, value, value);
+        assert_eq!(value, *candidate.eject_value());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_u8_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = u8;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_i8_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = i8;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_u16_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = u16;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_i16_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = i16;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_u32_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = u32;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_i32_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = i32;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_u64_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = u64;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_i64_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = i64;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_u128_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = u128;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_i128_from_field_clamped() {
+        let mut rng = TestRng::default();
+
+        type I = i128;
+        check_from_field_clamped::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped::<I>(Mode::Private, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Constant, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Public, &mut rng);
+        check_from_field_clamped_in_range::<I>(Mode::Private, &mut rng);
+    }
+}
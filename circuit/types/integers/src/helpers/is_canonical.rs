@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `true` if every bit in the representation of `self` is either zero or one.
+    ///
+    /// This method is intended for gadgets that accept externally-constructed bits,
+    /// which may not have been constrained to be boolean.
+    pub fn is_canonical(&self) -> Boolean<E> {
+        self.bits_le.iter().fold(Boolean::constant(true), |is_canonical, bit| {
+            // Note: The bit is checked as a base field element, as its value may not be boolean.
+            // A field element `b` is a bit if and only if `b * b == b`.
+            let bit = Field::from_boolean(bit);
+            is_canonical & bit.square().is_equal(&bit)
+        })
+    }
+
+    /// Enforces that every bit in the representation of `self` is either zero or one.
+    pub fn enforce_canonical(&self) {
+        for bit in &self.bits_le {
+            // Ensure (1 - b) * b = 0
+            // `b` must be either 0 or 1.
+            E::enforce(|| (E::one() - &**bit, bit, E::zero()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope_fails, Circuit};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_is_canonical<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample a random integer.
+            let candidate = Integer::<Circuit, I>::new(mode, Uniform::rand(rng));
+
+            Circuit::scope(format!("IsCanonical {mode} {i}"), || {
+                let is_canonical = candidate.is_canonical();
+                assert!(is_canonical.eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(I::BITS, 0, 0, 0),
+                    _ => assert_scope!(0, 0, 4 * I::BITS - 1, 4 * I::BITS - 1),
+                }
+            });
+            Circuit::scope(format!("EnforceCanonical {mode} {i}"), || {
+                candidate.enforce_canonical();
+                match mode {
+                    Mode::Constant => assert_scope!(0, 0, 0, 0),
+                    _ => assert_scope!(0, 0, 0, I::BITS),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_corrupted_bit<I: IntegerType>() {
+        // Replace the least significant bit with a variable that is not a bit.
+        let variable = Circuit::new_variable(Mode::Private, <Circuit as Environment>::BaseField::one().double());
+        let mut candidate = Integer::<Circuit, I>::new(Mode::Private, console::Integer::zero());
+        candidate.bits_le[0] = Boolean::from_variable_unchecked(variable);
+
+        // Ensure the malformed bit is detected, without making the circuit unsatisfiable.
+        Circuit::scope("IsCanonical corrupted", || {
+            let is_canonical = candidate.is_canonical();
+            assert!(!is_canonical.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        assert!(Circuit::is_satisfied());
+
+        // Ensure the malformed bit makes the circuit unsatisfiable.
+        Circuit::scope("EnforceCanonical corrupted", || {
+            candidate.enforce_canonical();
+            assert_scope_fails!();
+        });
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_u8_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = u8;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+
+    #[test]
+    fn test_i8_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = i8;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+
+    #[test]
+    fn test_u16_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = u16;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+
+    #[test]
+    fn test_i16_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = i16;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+
+    #[test]
+    fn test_u32_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = u32;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+
+    #[test]
+    fn test_i32_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = i32;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+
+    #[test]
+    fn test_u64_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = u64;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+
+    #[test]
+    fn test_i64_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = i64;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+
+    #[test]
+    fn test_u128_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = u128;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+
+    #[test]
+    fn test_i128_is_canonical() {
+        let mut rng = TestRng::default();
+
+        type I = i128;
+        check_is_canonical::<I>(Mode::Constant, &mut rng);
+        check_is_canonical::<I>(Mode::Public, &mut rng);
+        check_is_canonical::<I>(Mode::Private, &mut rng);
+        check_corrupted_bit::<I>();
+    }
+}
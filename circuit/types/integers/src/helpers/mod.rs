@@ -15,11 +15,18 @@
 use super::*;
 
 pub mod from_bits;
+pub mod from_bytes;
 pub mod from_field;
+pub mod from_field_clamped;
 pub mod from_field_lossy;
+pub mod is_canonical;
 pub mod msb;
+pub mod normalize_twos_complement;
 pub mod one;
+#[cfg(all(console, feature = "serde"))]
+pub mod serialize;
 pub mod to_bits;
+pub mod to_bytes;
 pub mod to_field;
 pub mod to_fields;
 pub mod to_scalar;
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes a new integer from a list of little-endian bits in two's complement form,
+    /// where the last bit in `bits_le` is interpreted as the sign bit.
+    ///
+    /// This method guarantees the following:
+    ///   1. If `bits_le` is shorter than the integer, then it is sign-extended.
+    ///   2. If `bits_le` is longer than the integer, then the excess bits must be a sign extension.
+    ///   3. If the integer is unsigned, then the sign bit must be zero.
+    pub fn normalize_twos_complement(bits_le: &[Boolean<E>]) -> Self {
+        // Retrieve the sign bit.
+        let sign = match bits_le.last() {
+            Some(sign) => sign,
+            None => E::halt("Cannot normalize an empty list of bits in two's complement form"),
+        };
+
+        match I::is_signed() {
+            true => {
+                // Check that all bits at or above the integer's sign bit are equal to the given sign bit.
+                if bits_le.len() as u64 > I::BITS {
+                    bits_le[(I::BITS as usize - 1)..].iter().for_each(|bit| E::assert_eq(bit, sign));
+                }
+                // Sign-extend the bits to the size of the integer.
+                let mut bits = bits_le.iter().take(I::BITS as usize).cloned().collect::<Vec<_>>();
+                bits.resize(I::BITS as usize, sign.clone());
+                Self { bits_le: bits, phantom: Default::default() }
+            }
+            false => {
+                // Check that the sign bit is zero, as an unsigned integer cannot represent a negative value.
+                // Note: If `bits_le` is longer than the integer, the sign bit is among the excess bits, and
+                // is checked to be zero by `from_bits_le`.
+                if (bits_le.len() as u64) <= I::BITS {
+                    E::assert_eq(sign, E::zero());
+                }
+                Self::from_bits_le(bits_le)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope_fails, Circuit};
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 128;
+
+    /// Returns the little-endian bits of `value`, truncated or sign-extended to `num_bits`.
+    fn sample_bits(mode: Mode, value: i128, num_bits: usize) -> Vec<Boolean<Circuit>> {
+        (0..num_bits).map(|i| Boolean::new(mode, (value >> i.min(127)) & 1 == 1)).collect()
+    }
+
+    fn check_normalize_twos_complement<I: IntegerType + TryFrom<i128>>(mode: Mode, value: i128, num_bits: usize) {
+        let bits_le = sample_bits(mode, value, num_bits);
+
+        // Compute the two's complement interpretation of the truncated bits.
+        let shift = 128 - num_bits.min(128);
+        let interpreted = (value << shift) >> shift;
+        let expected = I::try_from(interpreted).ok();
+
+        match (expected, mode) {
+            (Some(expected), _) => Circuit::scope(format!("{mode} {value} {num_bits}"), || {
+                let candidate = Integer::<Circuit, I>::normalize_twos_complement(&bits_le);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_scope!();
+            }),
+            (None, Mode::Constant) => check_unary_operation_halts(bits_le, |bits_le: Vec<Boolean<Circuit>>| {
+                Integer::<Circuit, I>::normalize_twos_complement(&bits_le)
+            }),
+            (None, _) => Circuit::scope(format!("{mode} {value} {num_bits}"), || {
+                let _candidate = Integer::<Circuit, I>::normalize_twos_complement(&bits_le);
+                assert_scope_fails!();
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + TryFrom<i128>>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let value: i128 = Uniform::rand(&mut rng);
+            // Check an exact width, a narrower width, and a wider width.
+            check_normalize_twos_complement::<I>(mode, value, I::BITS as usize);
+            check_normalize_twos_complement::<I>(mode, value, I::BITS as usize / 2);
+            check_normalize_twos_complement::<I>(mode, value, I::BITS as usize + 8);
+            // Check a wider width, where the excess bits are a valid sign extension.
+            let value = (value << (128 - I::BITS)) >> (128 - I::BITS);
+            check_normalize_twos_complement::<I>(mode, value, I::BITS as usize + 8);
+        }
+    }
+
+    #[test]
+    fn test_i8_edge_cases() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // 0xFF is -1.
+            let candidate = Integer::<Circuit, i8>::normalize_twos_complement(&sample_bits(mode, 0xFF, 8));
+            assert_eq!(-1, *candidate.eject_value());
+            // 0x80 is -128.
+            let candidate = Integer::<Circuit, i8>::normalize_twos_complement(&sample_bits(mode, 0x80, 8));
+            assert_eq!(i8::MIN, *candidate.eject_value());
+            // 0x7F is 127.
+            let candidate = Integer::<Circuit, i8>::normalize_twos_complement(&sample_bits(mode, 0x7F, 8));
+            assert_eq!(i8::MAX, *candidate.eject_value());
+            // 0xF (4 bits) is -1.
+            let candidate = Integer::<Circuit, i8>::normalize_twos_complement(&sample_bits(mode, 0xF, 4));
+            assert_eq!(-1, *candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_i128_edge_cases() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // 128 set bits is -1.
+            let candidate = Integer::<Circuit, i128>::normalize_twos_complement(&sample_bits(mode, -1, 128));
+            assert_eq!(-1, *candidate.eject_value());
+            // A set MSB alone is i128::MIN.
+            let candidate = Integer::<Circuit, i128>::normalize_twos_complement(&sample_bits(mode, i128::MIN, 128));
+            assert_eq!(i128::MIN, *candidate.eject_value());
+            // 127 set bits below a clear MSB is i128::MAX.
+            let candidate = Integer::<Circuit, i128>::normalize_twos_complement(&sample_bits(mode, i128::MAX, 128));
+            assert_eq!(i128::MAX, *candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_u8_edge_cases() {
+        for mode in [Mode::Public, Mode::Private] {
+            // 0xFF is -1, which is not a valid u8.
+            let _candidate = Integer::<Circuit, u8>::normalize_twos_complement(&sample_bits(mode, 0xFF, 8));
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_normalize_twos_complement() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            run_test::<i8>(mode);
+            run_test::<i16>(mode);
+            run_test::<i32>(mode);
+            run_test::<i64>(mode);
+            run_test::<i128>(mode);
+            run_test::<u8>(mode);
+            run_test::<u16>(mode);
+            run_test::<u32>(mode);
+            run_test::<u64>(mode);
+            run_test::<u128>(mode);
+        }
+    }
+}
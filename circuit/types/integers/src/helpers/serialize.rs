@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+impl<E: Environment, I: IntegerType> Serialize for Integer<E, I> {
+    /// Serializes the integer circuit into a string (e.g. `42u32.private`), or as a (value, mode) pair.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => (self.eject_value(), self.eject_mode() as u8).serialize(serializer),
+        }
+    }
+}
+
+impl<'de, E: Environment, I: IntegerType> Deserialize<'de> for Integer<E, I> {
+    /// Deserializes the integer circuit from a string, or from a (value, mode) pair.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => {
+                let (value, mode) = <(console::Integer<E::Network, I>, u8)>::deserialize(deserializer)?;
+                let mode = <Mode as console::FromBytes>::read_le(&[mode][..]).map_err(de::Error::custom)?;
+                Ok(Integer::new(mode, value))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_serde_json<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        for _ in 0..ITERATIONS {
+            // Sample a new integer.
+            let expected = Integer::<Circuit, I>::new(mode, Uniform::rand(rng));
+
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected_string, serde_json::Value::from_str(&candidate_string).unwrap().as_str().unwrap());
+
+            // Deserialize
+            let candidate: Integer<Circuit, I> = serde_json::from_str(&candidate_string).unwrap();
+            assert_eq!(expected.eject_value(), candidate.eject_value());
+            assert_eq!(expected.eject_mode(), candidate.eject_mode());
+        }
+        Circuit::reset();
+    }
+
+    fn check_bincode<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        for _ in 0..ITERATIONS {
+            // Sample a new integer.
+            let expected = Integer::<Circuit, I>::new(mode, Uniform::rand(rng));
+
+            // Serialize
+            let expected_bytes = bincode::serialize(&(expected.eject_value(), mode as u8)).unwrap();
+            assert_eq!(expected_bytes, bincode::serialize(&expected).unwrap());
+
+            // Deserialize
+            let candidate: Integer<Circuit, I> = bincode::deserialize(&expected_bytes).unwrap();
+            assert_eq!(expected.eject_value(), candidate.eject_value());
+            assert_eq!(expected.eject_mode(), candidate.eject_mode());
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_serde_json::<I>(mode, &mut rng);
+            check_bincode::<I>(mode, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        run_test::<u8>();
+        run_test::<u16>();
+        run_test::<u32>();
+        run_test::<u64>();
+        run_test::<u128>();
+
+        run_test::<i8>();
+        run_test::<i16>();
+        run_test::<i32>();
+        run_test::<i64>();
+        run_test::<i128>();
+    }
+
+    #[test]
+    fn test_deserialize_fails() {
+        // Ensure an out-of-range value is rejected.
+        assert!(serde_json::from_str::<U8<Circuit>>("\"256u8.private\"").is_err());
+        assert!(serde_json::from_str::<I8<Circuit>>("\"-129i8.public\"").is_err());
+        assert!(serde_json::from_str::<U128<Circuit>>("\"340282366920938463463374607431768211456u128\"").is_err());
+        // Ensure a mismatched type or an invalid mode is rejected.
+        assert!(serde_json::from_str::<U8<Circuit>>("\"1u16.private\"").is_err());
+        assert!(serde_json::from_str::<U8<Circuit>>("\"1u8.secret\"").is_err());
+        // Ensure an invalid mode byte is rejected.
+        assert!(bincode::deserialize::<U8<Circuit>>(&[1u8, 3u8]).is_err());
+        // Ensure a truncated encoding is rejected.
+        assert!(bincode::deserialize::<U32<Circuit>>(&[1u8, 0u8]).is_err());
+        Circuit::reset();
+    }
+}
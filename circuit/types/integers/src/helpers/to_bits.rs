@@ -109,6 +109,43 @@ mod tests {
         }
     }
 
+    /// Checks that the big-endian bits are the reverse of the little-endian bits, and round-trip via `from_bits_be`.
+    fn check_bits_be_round_trip<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(&mut rng);
+            let candidate = Integer::<Circuit, I>::new(mode, expected);
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let bits_le = candidate.to_bits_le();
+                let bits_be = candidate.to_bits_be();
+                assert_eq!(bits_le.eject_value(), bits_be.iter().rev().cloned().collect::<Vec<_>>().eject_value());
+
+                let candidate = Integer::<Circuit, I>::from_bits_be(&bits_be);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+        }
+    }
+
+    #[test]
+    fn test_bits_be_round_trip() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_bits_be_round_trip::<u8>(mode);
+            check_bits_be_round_trip::<i8>(mode);
+            check_bits_be_round_trip::<u16>(mode);
+            check_bits_be_round_trip::<i16>(mode);
+            check_bits_be_round_trip::<u32>(mode);
+            check_bits_be_round_trip::<i32>(mode);
+            check_bits_be_round_trip::<u64>(mode);
+            check_bits_be_round_trip::<i64>(mode);
+            check_bits_be_round_trip::<u128>(mode);
+            check_bits_be_round_trip::<i128>(mode);
+        }
+    }
+
     /// Checks that the field element, when converted to little-endian bits, is well-formed.
     fn check_individual_bits_le<I: IntegerType>(candidate: Integer<Circuit, I>) {
         for (i, bit) in candidate.to_bits_le().iter().enumerate() {
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> ToBytes for Integer<E, I> {
+    type Byte = U8<E>;
+
+    /// Outputs the little-endian byte representation of `self`.
+    fn to_bytes_le(&self) -> Vec<Self::Byte> {
+        // Note: This is safe as the number of bits in every integer type is a multiple of 8.
+        self.bits_le.chunks(8).map(|bits_le| U8 { bits_le: bits_le.to_vec(), phantom: Default::default() }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_to_bytes_le<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let candidate = Integer::<Circuit, I>::new(mode, value);
+        Circuit::scope(name, || {
+            let candidate = candidate.to_bytes_le();
+            assert_eq!(I::BITS / 8, candidate.len() as u64);
+
+            // Ensure every byte matches.
+            let expected = (*value).to_bytes_le().unwrap();
+            for (expected_byte, candidate_byte) in expected.iter().zip_eq(candidate.iter()) {
+                assert_eq!(*expected_byte, *candidate_byte.eject_value());
+            }
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("ToBytes: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_to_bytes_le::<I>(&name, value, mode);
+        }
+
+        // Check the bounds of the integer type.
+        for value in [console::Integer::zero(), console::Integer::one(), console::Integer::MAX, console::Integer::MIN] {
+            let name = format!("ToBytes: {mode} {value}");
+            check_to_bytes_le::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, to_bytes_le);
+    test_integer_unary!(run_test, i16, to_bytes_le);
+    test_integer_unary!(run_test, i32, to_bytes_le);
+    test_integer_unary!(run_test, i64, to_bytes_le);
+    test_integer_unary!(run_test, i128, to_bytes_le);
+
+    test_integer_unary!(run_test, u8, to_bytes_le);
+    test_integer_unary!(run_test, u16, to_bytes_le);
+    test_integer_unary!(run_test, u32, to_bytes_le);
+    test_integer_unary!(run_test, u64, to_bytes_le);
+    test_integer_unary!(run_test, u128, to_bytes_le);
+}
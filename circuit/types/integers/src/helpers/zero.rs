@@ -21,6 +21,10 @@ impl<E: Environment, I: IntegerType> Zero for Integer<E, I> {
         Integer::constant(console::Integer::zero())
     }
 
+    /// Returns `true` if `self` is zero.
+    ///
+    /// For a constant, this folds to a constant boolean. Otherwise, the comparison is performed
+    /// on the field representation, which costs 2 constraints, and is cheaper than a NOR over the bits.
     fn is_zero(&self) -> Self::Boolean {
         self.is_equal(&Integer::zero())
     }
@@ -47,6 +51,8 @@ mod tests {
     use super::*;
     use snarkvm_circuit_environment::Circuit;
 
+    const ITERATIONS: u64 = 100;
+
     fn check_zero<I: IntegerType>() {
         Circuit::scope("Zero", || {
             assert_scope!(0, 0, 0, 0);
@@ -63,6 +69,29 @@ mod tests {
         Circuit::reset();
     }
 
+    fn check_is_zero<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Check zero, followed by random (likely nonzero) values.
+        let values = [console::Integer::zero(), console::Integer::one(), console::Integer::MIN, console::Integer::MAX];
+        for (i, value) in values.into_iter().chain((0..ITERATIONS).map(|_| Uniform::rand(&mut rng))).enumerate() {
+            let candidate = Integer::<Circuit, I>::new(mode, value);
+            Circuit::scope(format!("IsZero: {mode} {i}"), || {
+                let is_zero = candidate.is_zero();
+                assert_eq!(value == console::Integer::zero(), is_zero.eject_value());
+                // Note: The constants in scope are the bits of `Integer::zero()`.
+                match mode.is_constant() {
+                    true => {
+                        assert!(is_zero.is_constant());
+                        assert_scope!(I::BITS, 0, 0, 0);
+                    }
+                    false => assert_scope!(I::BITS, 0, 2, 2),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
     test_integer_static!(check_zero, i8, zero);
     test_integer_static!(check_zero, i16, zero);
     test_integer_static!(check_zero, i32, zero);
@@ -74,4 +103,16 @@ mod tests {
     test_integer_static!(check_zero, u32, zero);
     test_integer_static!(check_zero, u64, zero);
     test_integer_static!(check_zero, u128, zero);
+
+    test_integer_unary!(check_is_zero, i8, is_zero);
+    test_integer_unary!(check_is_zero, i16, is_zero);
+    test_integer_unary!(check_is_zero, i32, is_zero);
+    test_integer_unary!(check_is_zero, i64, is_zero);
+    test_integer_unary!(check_is_zero, i128, is_zero);
+
+    test_integer_unary!(check_is_zero, u8, is_zero);
+    test_integer_unary!(check_is_zero, u16, is_zero);
+    test_integer_unary!(check_is_zero, u32, is_zero);
+    test_integer_unary!(check_is_zero, u64, is_zero);
+    test_integer_unary!(check_is_zero, u128, is_zero);
 }
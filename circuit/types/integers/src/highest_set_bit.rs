@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the index of the most significant set bit of `self`, and a flag that is `true` if `self` is nonzero.
+    /// If `self` is zero, the index is zero, and the flag is `false`.
+    ///
+    /// For nonzero values, the index is `I::BITS - 1 - leading_zeros`. Note that for signed integers,
+    /// the index is taken over the two's complement bits, so the index of a negative value is `I::BITS - 1`.
+    pub fn highest_set_bit(&self) -> (U32<E>, Boolean<E>) {
+        // Set every bit below the most significant set bit, via a prefix-OR from the most significant bit.
+        let smeared = self.smear_right();
+
+        // The least significant bit of the prefix-OR is set if and only if any bit of `self` is set.
+        let is_nonzero = smeared.bits_le[0].clone();
+
+        // The number of set bits in the prefix-OR is one more than the index of the most significant set bit.
+        // Note: Subtracting the flag ensures the index is zero (rather than -1), when `self` is zero.
+        // Note: This is safe as the bitwidth is less than the base field modulus.
+        let count = smeared.bits_le.iter().fold(Field::zero(), |sum, bit| sum + Field::from_boolean(bit));
+        let index = U32::from_field(count - Field::from_boolean(&is_nonzero));
+
+        (index, is_nonzero)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_highest_set_bit<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected_is_nonzero = !value.is_zero();
+        let expected_index = match expected_is_nonzero {
+            true => I::BITS as u32 - 1 - (*value).leading_zeros(),
+            false => 0,
+        };
+        Circuit::scope(name, || {
+            let (candidate_index, candidate_is_nonzero) = a.highest_set_bit();
+            assert_eq!(expected_index, *candidate_index.eject_value());
+            assert_eq!(expected_is_nonzero, candidate_is_nonzero.eject_value());
+            // The prefix-OR costs `I::BITS - 1` constraints, and the decomposition of the index costs 33 constraints.
+            match mode.is_constant() {
+                true => assert_scope!(32, 0, 0, 0),
+                false => assert_scope!(0, 0, I::BITS + 31, I::BITS + 32),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("HighestSetBit: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_highest_set_bit::<I>(&name, value, mode);
+        }
+
+        check_highest_set_bit::<I>(&format!("HighestSetBit: {mode} zero"), console::Integer::zero(), mode);
+        check_highest_set_bit::<I>(&format!("HighestSetBit: {mode} one"), console::Integer::one(), mode);
+        check_highest_set_bit::<I>(&format!("HighestSetBit: {mode} max"), console::Integer::MAX, mode);
+        check_highest_set_bit::<I>(&format!("HighestSetBit: {mode} min"), console::Integer::MIN, mode);
+
+        // Check every power of two, which sets exactly the bit at the expected index.
+        for shift in 0..I::BITS as usize {
+            let name = format!("HighestSetBit: {mode} 1 << {shift}");
+            check_highest_set_bit::<I>(&name, console::Integer::new(I::one() << shift), mode);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("HighestSetBit: {mode} {value}");
+            check_highest_set_bit::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, highest_set_bit);
+    test_integer_unary!(run_test, i16, highest_set_bit);
+    test_integer_unary!(run_test, i32, highest_set_bit);
+    test_integer_unary!(run_test, i64, highest_set_bit);
+    test_integer_unary!(run_test, i128, highest_set_bit);
+
+    test_integer_unary!(run_test, u8, highest_set_bit);
+    test_integer_unary!(run_test, u16, highest_set_bit);
+    test_integer_unary!(run_test, u32, highest_set_bit);
+    test_integer_unary!(run_test, u64, highest_set_bit);
+    test_integer_unary!(run_test, u128, highest_set_bit);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, highest_set_bit, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, highest_set_bit, exhaustive);
+}
@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Ilog<Self> for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the floor of the logarithm of `self`, with respect to the constant `base`.
+    /// This operation halts if `base` is not a constant or is less than two,
+    /// and halts, or fails to satisfy the circuit, if `self` is not positive.
+    fn ilog(&self, base: &Integer<E, I>) -> Self::Output {
+        // Ensure the base is a constant that is at least two.
+        // Note: A variable base would require a variable number of comparisons.
+        if !base.is_constant() {
+            E::halt("The base of an integer logarithm must be a constant")
+        }
+        let base = *base.eject_value();
+        if base < I::one() + I::one() {
+            E::halt("The base of an integer logarithm must be at least two")
+        }
+        // Ensure a constant is positive.
+        if self.is_constant() && *self.eject_value() <= I::zero() {
+            E::halt("Attempted to take the logarithm of a non-positive integer")
+        }
+
+        // Ensure `self` is positive.
+        E::assert(self.is_greater_than_or_equal(&Integer::one()));
+
+        // Compare `self` against each power of the base that fits in the integer domain,
+        // and count the number of powers that are less than or equal to `self`.
+        let mut count = Field::zero();
+        let mut power = base;
+        loop {
+            let is_greater_than_or_equal =
+                self.is_greater_than_or_equal(&Integer::constant(console::Integer::new(power)));
+            count += Field::from_boolean(&is_greater_than_or_equal);
+            match power.checked_mul(&base) {
+                Some(next_power) => power = next_power,
+                None => break,
+            }
+        }
+
+        // Decompose the count into an unsigned 32-bit integer.
+        // Note: This is safe as the count is at most `I::BITS`.
+        U32::from_field(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 64;
+
+    /// Returns the floor of the logarithm of `value` with respect to `base`, or `None` if it is undefined.
+    fn ilog<I: IntegerType>(value: I, base: I) -> Option<u32> {
+        if value <= I::zero() || base < I::one() + I::one() {
+            return None;
+        }
+        let (mut result, mut power) = (0, base);
+        while power <= value {
+            result += 1;
+            match power.checked_mul(&base) {
+                Some(next_power) => power = next_power,
+                None => break,
+            }
+        }
+        Some(result)
+    }
+
+    fn check_ilog<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        base: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let b = Integer::<Circuit, I>::constant(base);
+        match ilog(*value, *base) {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.ilog(&b);
+                assert_eq!(expected, *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            }),
+            None => match mode {
+                Mode::Constant => check_operation_halts(&a, &b, Integer::ilog),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.ilog(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let two = console::Integer::one() + console::Integer::one();
+        let three = two + console::Integer::one();
+        let ten = three + three + three + console::Integer::one();
+
+        for base in [two, three, ten] {
+            for i in 0..ITERATIONS {
+                let name = format!("Ilog: {mode} {base} {i}");
+                let value = Uniform::rand(&mut rng);
+                check_ilog::<I>(&name, value, base, mode);
+            }
+
+            // Check the zero, one, and bounds cases.
+            for value in [console::Integer::zero(), console::Integer::one(), console::Integer::MAX] {
+                let name = format!("Ilog: {mode} {base} {value}");
+                check_ilog::<I>(&name, value, base, mode);
+            }
+
+            // Check that powers of the base, and their neighbors, are rounded down correctly.
+            let mut power = *base;
+            loop {
+                for value in [power, power.wrapping_sub(&I::one()), power.wrapping_add(&I::one())] {
+                    let name = format!("Ilog: {mode} {base} {value}");
+                    check_ilog::<I>(&name, console::Integer::new(value), base, mode);
+                }
+                match power.checked_mul(&*base) {
+                    Some(next_power) => power = next_power,
+                    None => break,
+                }
+            }
+        }
+    }
+
+    fn run_invalid_base_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::MAX);
+        for base in [console::Integer::zero(), console::Integer::one()] {
+            check_operation_halts(&a, &Integer::constant(base), Integer::ilog);
+        }
+        // Check that a variable base halts.
+        let base = console::Integer::one() + console::Integer::one();
+        check_operation_halts(&a, &Integer::new(Mode::Private, base), Integer::ilog);
+    }
+
+    test_integer_unary!(run_test, u8, ilog);
+    test_integer_unary!(run_test, u16, ilog);
+    test_integer_unary!(run_test, u32, ilog);
+    test_integer_unary!(run_test, u64, ilog);
+    test_integer_unary!(run_test, u128, ilog);
+
+    test_integer_unary!(run_test, i8, ilog);
+    test_integer_unary!(run_test, i64, ilog);
+
+    test_integer_unary!(run_invalid_base_test, u8, ilog_invalid_base);
+    test_integer_unary!(run_invalid_base_test, u64, ilog_invalid_base);
+}
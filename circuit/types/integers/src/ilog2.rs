@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Ilog2 for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the floor of the base-2 logarithm of `self`, which is the position of the most significant set bit.
+    /// This operation halts, or fails to satisfy the circuit, if `self` is not positive.
+    fn ilog2(&self) -> Self::Output {
+        // Ensure a constant is positive.
+        if self.is_constant() && *self.eject_value() <= I::zero() {
+            E::halt("Attempted to take the logarithm of a non-positive integer")
+        }
+        // Ensure `self` is non-negative.
+        if I::is_signed() {
+            E::assert(!self.msb());
+        }
+
+        // Set every bit below the most significant set bit, so that the number of set bits is `ilog2(self) + 1`.
+        let smeared = self.smear_right();
+        let count = smeared.bits_le.iter().fold(Field::zero(), |sum, bit| sum + Field::from_boolean(bit));
+
+        // Decompose `count - 1` into an unsigned 32-bit integer.
+        // Note: If `self` is zero, then `count - 1` wraps around the base field modulus, and the decomposition fails.
+        U32::from_field(count - Field::one())
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Ilog2<Output = U32<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match (case, I::is_signed()) {
+            (Mode::Constant, _) => Count::is(32, 0, 0, 0),
+            (_, true) => Count::is(0, 0, I::BITS + 31, I::BITS + 33),
+            (_, false) => Count::is(0, 0, I::BITS + 31, I::BITS + 32),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Ilog2<Output = U32<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::UnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_ilog2<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        match *value <= I::zero() {
+            true => match mode {
+                Mode::Constant => check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.ilog2()),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.ilog2();
+                    assert_count_fails!(Ilog2(Integer<I>) => Integer<u32>, &mode);
+                }),
+            },
+            false => {
+                let expected = I::BITS as u32 - 1 - (*value).leading_zeros();
+                Circuit::scope(name, || {
+                    let candidate = a.ilog2();
+                    assert_eq!(expected, *candidate.eject_value());
+                    assert_count!(Ilog2(Integer<I>) => Integer<u32>, &mode);
+                    assert_output_mode!(Ilog2(Integer<I>) => Integer<u32>, &mode, candidate);
+                })
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("Ilog2: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_ilog2::<I>(&name, value, mode);
+        }
+
+        // Check the zero, one, and bounds cases.
+        for value in [console::Integer::zero(), console::Integer::one(), console::Integer::MAX, console::Integer::MIN] {
+            let name = format!("Ilog2: {mode} {value}");
+            check_ilog2::<I>(&name, value, mode);
+        }
+
+        // Check that powers of two, and their neighbors, are rounded down correctly.
+        for shift in 0..I::BITS as usize {
+            let power = I::one() << shift;
+            for value in [power, power.wrapping_sub(&I::one()), power.wrapping_add(&I::one())] {
+                let name = format!("Ilog2: {mode} {value}");
+                check_ilog2::<I>(&name, console::Integer::new(value), mode);
+            }
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + UnwindSafe>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("Ilog2: {mode} {value}");
+            check_ilog2::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, ilog2);
+    test_integer_unary!(run_test, i16, ilog2);
+    test_integer_unary!(run_test, i32, ilog2);
+    test_integer_unary!(run_test, i64, ilog2);
+    test_integer_unary!(run_test, i128, ilog2);
+
+    test_integer_unary!(run_test, u8, ilog2);
+    test_integer_unary!(run_test, u16, ilog2);
+    test_integer_unary!(run_test, u32, ilog2);
+    test_integer_unary!(run_test, u64, ilog2);
+    test_integer_unary!(run_test, u128, ilog2);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, ilog2, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, ilog2, exhaustive);
+}
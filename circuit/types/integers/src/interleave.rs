@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the Morton (Z-order) encoding of `a` and `b`, as an integer of type `W` with twice the bit width of `I`.
+    /// The bits of `a` occupy the even positions of the output, and the bits of `b` occupy the odd positions.
+    ///
+    /// This is a reordering of the bits, and requires no constraints.
+    /// Halts if the bit width of `W` is not twice the bit width of `I`.
+    pub fn interleave<W: IntegerType>(a: &Self, b: &Self) -> Integer<E, W> {
+        // Ensure the output type is twice the bit width of the inputs.
+        if 2 * I::BITS != W::BITS {
+            E::halt(format!("Cannot interleave two {}-bit integers into a {}-bit integer", I::BITS, W::BITS))
+        }
+        let bits_le = a.bits_le.iter().zip_eq(b.bits_le.iter()).flat_map(|(a, b)| [a.clone(), b.clone()]).collect();
+        Integer { bits_le, phantom: Default::default() }
+    }
+
+    /// Returns the two integers of type `T` whose Morton (Z-order) encoding is `self`, the inverse of `interleave`.
+    /// The first integer is formed from the even bits of `self`, and the second from the odd bits.
+    ///
+    /// This is a reordering of the bits, and requires no constraints.
+    /// Halts if the bit width of `T` is not half the bit width of `I`.
+    pub fn deinterleave<T: IntegerType>(&self) -> (Integer<E, T>, Integer<E, T>) {
+        // Ensure the output type is half the bit width of `self`.
+        if 2 * T::BITS != I::BITS {
+            E::halt(format!("Cannot deinterleave a {}-bit integer into two {}-bit integers", I::BITS, T::BITS))
+        }
+        let even = Integer { bits_le: self.bits_le.iter().step_by(2).cloned().collect(), phantom: Default::default() };
+        let odd =
+            Integer { bits_le: self.bits_le.iter().skip(1).step_by(2).cloned().collect(), phantom: Default::default() };
+        (even, odd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_interleave<I: IntegerType, W: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        // Compute the expected value by alternating the bits of the inputs.
+        let expected_le = first.to_bits_le().into_iter().zip_eq(second.to_bits_le()).flat_map(|(a, b)| [a, b]);
+        let expected = console::Integer::<_, W>::from_bits_le(&expected_le.collect::<Vec<_>>()).unwrap();
+        Circuit::scope(name, || {
+            let candidate = Integer::<Circuit, I>::interleave::<W>(&a, &b);
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure the encoding deinterleaves back to the original values.
+            let (candidate_a, candidate_b) = candidate.deinterleave::<I>();
+            assert_eq!(first, candidate_a.eject_value());
+            assert_eq!(second, candidate_b.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, W: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("Interleave: {mode_a} {mode_b} {i}");
+            check_interleave::<I, W>(&name, Uniform::rand(&mut rng), Uniform::rand(&mut rng), mode_a, mode_b);
+        }
+
+        check_interleave::<I, W>("0, MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        check_interleave::<I, W>("MAX, 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_interleave::<I, W>("MIN, 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+
+        // Ensure mismatched bit widths halt.
+        let a = Integer::<Circuit, I>::new(mode_a, console::Integer::zero());
+        check_unary_operation_halts(&a, |a| Integer::<Circuit, I>::interleave::<I>(a, a));
+        let c = Integer::<Circuit, W>::new(mode_a, console::Integer::zero());
+        check_unary_operation_halts(&c, |c| c.deinterleave::<W>());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_interleave() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<u8, u16>(mode_a, mode_b);
+                run_test::<i8, i16>(mode_a, mode_b);
+                run_test::<u16, i32>(mode_a, mode_b);
+                run_test::<u32, u64>(mode_a, mode_b);
+                run_test::<i64, u128>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_interleave_matches_reference() {
+        /// Returns the Morton encoding of two bytes, by spreading each byte over the even bits of a `u16`.
+        fn morton_u8(a: u8, b: u8) -> u16 {
+            let spread = |x: u8| {
+                let x = x as u16;
+                let x = (x | (x << 4)) & 0x0f0f;
+                let x = (x | (x << 2)) & 0x3333;
+                (x | (x << 1)) & 0x5555
+            };
+            spread(a) | (spread(b) << 1)
+        }
+
+        for a in (u8::MIN..=u8::MAX).step_by(3) {
+            for b in (u8::MIN..=u8::MAX).step_by(5) {
+                let first = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::new(a));
+                let second = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::new(b));
+                let candidate = Integer::interleave::<u16>(&first, &second);
+                assert_eq!(morton_u8(a, b), *candidate.eject_value());
+                Circuit::reset();
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the multiplicative inverse of `self` modulo a constant prime `modulus`, along with a flag
+    /// indicating whether `self` is invertible. If `self` is zero, this returns zero and `false`.
+    /// This operation is only defined for unsigned integers.
+    ///
+    /// Note: This assumes that `self` is less than `modulus`, and that `modulus` is prime.
+    /// The primality of `modulus` is not checked, and if it does not hold, the circuit may not be satisfied.
+    pub fn inv_mod(&self, modulus: &Self) -> (Self, Boolean<E>) {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("Modular inversion is only defined for unsigned integers")
+        }
+        // Ensure the modulus is a constant, and at least two.
+        if !modulus.is_constant() {
+            E::halt("The modulus of a modular inversion must be a constant")
+        }
+        if *modulus.eject_value() < I::one() + I::one() {
+            E::halt("The modulus of a modular inversion must be a prime")
+        }
+
+        // Compute the exponent `modulus - 2`, as `self^(modulus - 2)` is the inverse of a nonzero `self`.
+        let exponent = *modulus.eject_value() - I::one() - I::one();
+
+        // Determine the variable mode.
+        if self.is_constant() {
+            // Ensure `self` is reduced, as in the non-constant case.
+            if self.eject_value() >= modulus.eject_value() {
+                E::halt("The operand of a modular inversion must be less than the modulus")
+            }
+            // Compute the inverse and return the new constants.
+            match *self.eject_value() == I::zero() {
+                true => (Self::zero(), Boolean::constant(false)),
+                false => (
+                    witness!(|self, modulus| console::Integer::new(pow_mod::pow_mod(*self, exponent, *modulus))),
+                    Boolean::constant(true),
+                ),
+            }
+        } else {
+            // Witness the inverse, using Fermat's little theorem.
+            let inverse: Integer<E, I> =
+                witness!(|self, modulus| console::Integer::new(pow_mod::pow_mod(*self, exponent, *modulus)));
+
+            // Ensure that the inverse is reduced, i.e. that `modulus - 1 - inverse` fits in `I::BITS` bits.
+            (modulus.to_field() - Field::one() - inverse.to_field()).to_lower_bits_le(I::BITS as usize);
+
+            // Ensure that `self * inverse % modulus` is one if `self` is nonzero, and zero otherwise.
+            // Note: As the modulus is prime, the product is one for exactly one reduced inverse of a nonzero `self`.
+            let is_invertible = !self.is_zero();
+            E::assert_eq(self.mul_mod(&inverse, modulus).to_field(), Field::from_boolean(&is_invertible));
+
+            // Return zero if `self` is not invertible, as the witnessed inverse is unconstrained in this case.
+            (Self::ternary(&is_invertible, &inverse, &Self::zero()), is_invertible)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_inv_mod<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        modulus: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let m = Integer::<Circuit, I>::constant(modulus);
+        let (value, modulus) = (value.to_u128().unwrap(), modulus.to_u128().unwrap());
+
+        Circuit::scope(name, || {
+            let (candidate, is_invertible) = a.inv_mod(&m);
+            let candidate = candidate.eject_value().to_u128().unwrap();
+            match value {
+                0 => {
+                    assert!(!is_invertible.eject_value());
+                    assert_eq!(0, candidate);
+                }
+                _ => {
+                    assert!(is_invertible.eject_value());
+                    assert!(candidate < modulus);
+                    assert_eq!(1, value * candidate % modulus);
+                }
+            }
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Note: These primes fit in every integer type, and keep the reference product within a `u128`.
+        let primes = ["2", "7", "251"].map(|prime| I::from_str(prime).unwrap());
+
+        for prime in primes {
+            for i in 0..ITERATIONS {
+                let value = I::rand(&mut rng) % prime;
+                let name = format!("InvMod: {mode} % {prime} {i}");
+                check_inv_mod::<I>(&name, console::Integer::new(value), console::Integer::new(prime), mode);
+            }
+
+            // Check the zero and largest values.
+            let name = format!("InvMod: {mode} % {prime} zero");
+            check_inv_mod::<I>(&name, console::Integer::zero(), console::Integer::new(prime), mode);
+            let name = format!("InvMod: {mode} % {prime} largest");
+            check_inv_mod::<I>(&name, console::Integer::new(prime - I::one()), console::Integer::new(prime), mode);
+        }
+    }
+
+    #[test]
+    fn test_inv_mod_halts() {
+        let zero = console::Integer::<_, u8>::zero();
+        let one = console::Integer::<_, u8>::one();
+
+        // Ensure a non-constant modulus halts.
+        let a = Integer::<Circuit, u8>::new(Mode::Private, one);
+        let m = Integer::<Circuit, u8>::new(Mode::Private, one + one + one);
+        check_operation_halts(&a, &m, |a, m| a.inv_mod(m));
+
+        // Ensure a modulus less than two halts.
+        for modulus in [zero, one] {
+            let m = Integer::<Circuit, u8>::constant(modulus);
+            check_operation_halts(&a, &m, |a, m| a.inv_mod(m));
+        }
+
+        // Ensure a signed integer type halts.
+        let a = Integer::<Circuit, i8>::new(Mode::Private, console::Integer::one());
+        let m = Integer::<Circuit, i8>::constant(console::Integer::one() + console::Integer::one());
+        check_operation_halts(&a, &m, |a, m| a.inv_mod(m));
+
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, u8, inv_mod);
+    test_integer_unary!(run_test, u16, inv_mod);
+    test_integer_unary!(run_test, u32, inv_mod);
+    test_integer_unary!(run_test, u64, inv_mod);
+    test_integer_unary!(run_test, u128, inv_mod);
+}
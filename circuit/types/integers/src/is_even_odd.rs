@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `true` if `self` is even.
+    ///
+    /// This is the negation of the least significant bit, and requires no constraints.
+    pub fn is_even(&self) -> Boolean<E> {
+        !self.is_odd()
+    }
+
+    /// Returns `true` if `self` is odd.
+    ///
+    /// This is the least significant bit, and requires no constraints.
+    /// Note that this holds for negative signed integers as well, as they are in two's complement form.
+    pub fn is_odd(&self) -> Boolean<E> {
+        self.bits_le[0].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_is_even_odd<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected_odd = *value % (I::one() + I::one()) != I::zero();
+        Circuit::scope(name, || {
+            let candidate = a.is_odd();
+            assert_eq!(expected_odd, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+
+            let candidate = a.is_even();
+            assert_eq!(!expected_odd, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("IsEvenOdd: {mode} {i}");
+            check_is_even_odd::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        // Check the values around zero, and the bounds.
+        check_is_even_odd::<I>("Zero", console::Integer::zero(), mode);
+        check_is_even_odd::<I>("One", console::Integer::one(), mode);
+        check_is_even_odd::<I>("MIN", console::Integer::MIN, mode);
+        check_is_even_odd::<I>("MAX", console::Integer::MAX, mode);
+        if I::is_signed() {
+            check_is_even_odd::<I>("Negative One", -console::Integer::one(), mode);
+            check_is_even_odd::<I>("Negative Two", -console::Integer::one() - console::Integer::one(), mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, is_even_odd);
+    test_integer_unary!(run_test, i16, is_even_odd);
+    test_integer_unary!(run_test, i32, is_even_odd);
+    test_integer_unary!(run_test, i64, is_even_odd);
+    test_integer_unary!(run_test, i128, is_even_odd);
+
+    test_integer_unary!(run_test, u8, is_even_odd);
+    test_integer_unary!(run_test, u16, is_even_odd);
+    test_integer_unary!(run_test, u32, is_even_odd);
+    test_integer_unary!(run_test, u64, is_even_odd);
+    test_integer_unary!(run_test, u128, is_even_odd);
+}
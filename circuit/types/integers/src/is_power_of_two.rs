@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> IsPowerOfTwo for Integer<E, I> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is a power of two.
+    /// This operation is only defined for unsigned integers.
+    fn is_power_of_two(&self) -> Self::Output {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("The power of two check is only defined for unsigned integers")
+        }
+
+        // An unsigned integer is a power of two if and only if exactly one of its bits is set.
+        // Note: This is cheaper than checking `x != 0 && (x & (x - 1)) == 0`, as the bits are summed in the base field,
+        // which is safe as the bitwidth is less than the base field modulus.
+        let count = self.bits_le.iter().fold(Field::zero(), |sum, bit| sum + Field::from_boolean(bit));
+        count.is_equal(&Field::one())
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn IsPowerOfTwo<Output = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(1, 0, 0, 0),
+            _ => Count::is(0, 0, 2, 2),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn IsPowerOfTwo<Output = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::UnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_is_power_of_two<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = (*value).to_u128().unwrap().is_power_of_two();
+        Circuit::scope(name, || {
+            let candidate = a.is_power_of_two();
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Integer<Circuit, I>, IsPowerOfTwo<Output = Boolean<Circuit>>, &mode);
+            assert_output_mode!(Integer<Circuit, I>, IsPowerOfTwo<Output = Boolean<Circuit>>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("IsPowerOfTwo: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_is_power_of_two::<I>(&name, value, mode);
+        }
+
+        // Check the zero, one, and bounds cases.
+        for value in [console::Integer::zero(), console::Integer::one(), console::Integer::MAX] {
+            let name = format!("IsPowerOfTwo: {mode} {value}");
+            check_is_power_of_two::<I>(&name, value, mode);
+        }
+
+        // Check that exact powers of two are accepted, and their neighbors are rejected.
+        for shift in 0..I::BITS as usize {
+            let power = I::one() << shift;
+            for value in [power, power.wrapping_sub(&I::one()), power.wrapping_add(&I::one())] {
+                let name = format!("IsPowerOfTwo: {mode} {value}");
+                check_is_power_of_two::<I>(&name, console::Integer::new(value), mode);
+            }
+        }
+    }
+
+    fn run_signed_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::one());
+        check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.is_power_of_two());
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("IsPowerOfTwo: {mode} {value}");
+            check_is_power_of_two::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, u8, is_power_of_two);
+    test_integer_unary!(run_test, u16, is_power_of_two);
+    test_integer_unary!(run_test, u32, is_power_of_two);
+    test_integer_unary!(run_test, u64, is_power_of_two);
+    test_integer_unary!(run_test, u128, is_power_of_two);
+
+    test_integer_unary!(run_signed_test, i8, is_power_of_two_halts);
+    test_integer_unary!(run_signed_test, i64, is_power_of_two_halts);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, is_power_of_two, exhaustive);
+}
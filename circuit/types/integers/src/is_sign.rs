@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `true` if `self` is negative.
+    /// Note that unsigned integers are never negative.
+    ///
+    /// For signed integers, this is the most significant bit, and requires no constraints.
+    pub fn is_negative(&self) -> Boolean<E> {
+        match I::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        }
+    }
+
+    /// Returns `true` if `self` is positive, i.e. if `self` is neither negative nor zero.
+    pub fn is_positive(&self) -> Boolean<E> {
+        !self.is_negative() & !self.is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_is_sign<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected_negative = *value < I::zero();
+        let expected_positive = *value > I::zero();
+        Circuit::scope(name, || {
+            let candidate = a.is_negative();
+            assert_eq!(expected_negative, candidate.eject_value());
+            assert_eq!((0, 0, 0), variables_and_constraints_in_scope());
+
+            let candidate = a.is_positive();
+            assert_eq!(expected_positive, candidate.eject_value());
+            match (mode.is_constant(), I::is_signed()) {
+                (true, _) => assert_eq!((0, 0, 0), variables_and_constraints_in_scope()),
+                (false, true) => assert_eq!((0, 3, 3), variables_and_constraints_in_scope()),
+                (false, false) => assert_eq!((0, 2, 2), variables_and_constraints_in_scope()),
+            }
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    /// Returns the number of public variables, private variables, and constraints in scope.
+    fn variables_and_constraints_in_scope() -> (u64, u64, u64) {
+        (Circuit::num_public_in_scope(), Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("IsSign: {mode} {i}");
+            check_is_sign::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        // Check the values around zero, and the bounds.
+        check_is_sign::<I>("Zero", console::Integer::zero(), mode);
+        check_is_sign::<I>("One", console::Integer::one(), mode);
+        check_is_sign::<I>("MIN", console::Integer::MIN, mode);
+        check_is_sign::<I>("MAX", console::Integer::MAX, mode);
+        if I::is_signed() {
+            check_is_sign::<I>("Negative One", -console::Integer::one(), mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, is_sign);
+    test_integer_unary!(run_test, i16, is_sign);
+    test_integer_unary!(run_test, i32, is_sign);
+    test_integer_unary!(run_test, i64, is_sign);
+    test_integer_unary!(run_test, i128, is_sign);
+
+    test_integer_unary!(run_test, u8, is_sign);
+    test_integer_unary!(run_test, u16, is_sign);
+    test_integer_unary!(run_test, u32, is_sign);
+    test_integer_unary!(run_test, u64, is_sign);
+    test_integer_unary!(run_test, u128, is_sign);
+}
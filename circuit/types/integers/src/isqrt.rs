@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Isqrt for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the floor of the square root of `self`.
+    /// This operation halts, or fails to satisfy the circuit, if `self` is negative.
+    fn isqrt(&self) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() {
+            // Ensure the constant is non-negative.
+            if *self.eject_value() < I::zero() {
+                E::halt("Attempted to take the square root of a negative integer")
+            }
+            // Compute the square root and return the new constant.
+            witness!(|self| console::Integer::new(floor_sqrt(*self)))
+        } else {
+            // Ensure `self` is non-negative.
+            if I::is_signed() {
+                E::assert(!self.msb());
+            }
+
+            // Witness the square root of `self`.
+            let root: Integer<E, I> = witness!(|self| console::Integer::new(floor_sqrt(*self)));
+
+            // Ensure the square root fits in the lower half of the bits, so that its square cannot exceed the
+            // integer domain, and so that the following field arithmetic cannot wrap around the base field modulus.
+            let num_half_bits = I::BITS as usize / 2;
+            Boolean::assert_bits_are_zero(&root.bits_le[num_half_bits..]);
+
+            // Compute `self - root^2` and `2 * root`, in the base field.
+            let root_field = root.to_field();
+            let remainder = self.to_field() - root_field.square();
+            let double_root = root_field.double();
+
+            // Ensure that `root^2 <= self < (root + 1)^2`, which is equivalent to `0 <= self - root^2 <= 2 * root`.
+            // Note: Both differences are at most `2 * root < 2^(num_half_bits + 1)` when the root is correct,
+            // and are negative, and hence exceed this bound in the base field, otherwise.
+            remainder.to_lower_bits_le(num_half_bits + 1);
+            (double_root - remainder).to_lower_bits_le(num_half_bits + 1);
+
+            root
+        }
+    }
+}
+
+/// Returns the floor of the square root of `value`, or zero if `value` is negative.
+fn floor_sqrt<I: IntegerType>(value: I) -> I {
+    // Determine the bits of the square root, starting from the most significant bit.
+    // Note: The square root of `value` is less than `2^(I::BITS / 2)`.
+    let mut root = I::zero();
+    for i in (0..I::BITS as usize / 2).rev() {
+        let candidate = root | (I::one() << i);
+        if let Some(square) = candidate.checked_mul(&candidate) {
+            if square <= value {
+                root = candidate;
+            }
+        }
+    }
+    root
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Isqrt<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match (case, I::is_signed()) {
+            (Mode::Constant, _) => Count::is(I::BITS, 0, 0, 0),
+            (_, true) => Count::is(0, 0, (2 * I::BITS) + 3, (2 * I::BITS) + 7),
+            (_, false) => Count::is(0, 0, (2 * I::BITS) + 3, (2 * I::BITS) + 6),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Isqrt<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::UnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_isqrt<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        match *value < I::zero() {
+            true => match mode {
+                Mode::Constant => check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.isqrt()),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.isqrt();
+                    assert_count_fails!(Isqrt(Integer<I>) => Integer<I>, &mode);
+                }),
+            },
+            false => Circuit::scope(name, || {
+                let candidate = a.isqrt();
+                // Check that `root^2 <= value < (root + 1)^2`.
+                let (value, root) = ((*value).to_u128().unwrap(), (*candidate.eject_value()).to_u128().unwrap());
+                assert!(root * root <= value);
+                assert!((root + 1).checked_mul(root + 1).map_or(true, |square| square > value));
+                assert_count!(Isqrt(Integer<I>) => Integer<I>, &mode);
+                assert_output_mode!(Isqrt(Integer<I>) => Integer<I>, &mode, candidate);
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("Isqrt: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_isqrt::<I>(&name, value, mode);
+        }
+
+        // Check the zero, one, and bounds cases.
+        for value in [console::Integer::zero(), console::Integer::one(), console::Integer::MAX, console::Integer::MIN] {
+            let name = format!("Isqrt: {mode} {value}");
+            check_isqrt::<I>(&name, value, mode);
+        }
+
+        // Check that perfect squares, and their neighbors, are rounded down correctly.
+        for shift in 0..I::BITS as usize / 2 {
+            let root = I::one() << shift;
+            let square = root * root;
+            for value in [square, square.wrapping_sub(&I::one()), square.wrapping_add(&I::one())] {
+                let name = format!("Isqrt: {mode} {value}");
+                check_isqrt::<I>(&name, console::Integer::new(value), mode);
+            }
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + UnwindSafe>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("Isqrt: {mode} {value}");
+            check_isqrt::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, isqrt);
+    test_integer_unary!(run_test, i16, isqrt);
+    test_integer_unary!(run_test, i32, isqrt);
+    test_integer_unary!(run_test, i64, isqrt);
+    test_integer_unary!(run_test, i128, isqrt);
+
+    test_integer_unary!(run_test, u8, isqrt);
+    test_integer_unary!(run_test, u16, isqrt);
+    test_integer_unary!(run_test, u32, isqrt);
+    test_integer_unary!(run_test, u64, isqrt);
+    test_integer_unary!(run_test, u128, isqrt);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, isqrt, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, isqrt, exhaustive);
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> LeadingOnes for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of leading ones in the binary representation of `self`.
+    fn leading_ones(&self) -> Self::Output {
+        // The leading ones of `self` are the leading zeros of its complement.
+        // Note: If every bit of `self` is set, then the result is `I::BITS`.
+        (!self).leading_zeros()
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn LeadingOnes<Output = U32<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(32, 0, 0, 0),
+            _ => Count::is(0, 0, I::BITS + 31, I::BITS + 32),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn LeadingOnes<Output = U32<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_leading_ones<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = (*value).leading_ones();
+        Circuit::scope(name, || {
+            let candidate = a.leading_ones();
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(LeadingOnes(Integer<I>) => Integer<u32>, &mode);
+            assert_output_mode!(LeadingOnes(Integer<I>) => Integer<u32>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("LeadingOnes: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_leading_ones::<I>(&name, value, mode);
+        }
+
+        check_leading_ones::<I>(&format!("LeadingOnes: {mode} zero"), console::Integer::zero(), mode);
+        check_leading_ones::<I>(&format!("LeadingOnes: {mode} one"), console::Integer::one(), mode);
+        check_leading_ones::<I>(&format!("LeadingOnes: {mode} max"), console::Integer::MAX, mode);
+        check_leading_ones::<I>(&format!("LeadingOnes: {mode} min"), console::Integer::MIN, mode);
+        check_leading_ones::<I>(&format!("LeadingOnes: {mode} all ones"), console::Integer::new(!I::zero()), mode);
+
+        // Check that each complement of a mask of the form `2^i - 1` returns `I::BITS - i`.
+        for i in 0..I::BITS as usize {
+            let name = format!("LeadingOnes: {mode} !(2^{i} - 1)");
+            check_leading_ones::<I>(&name, console::Integer::new(!(I::one() << i).wrapping_sub(&I::one())), mode);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("LeadingOnes: {mode}");
+            check_leading_ones::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, leading_ones);
+    test_integer_unary!(run_test, i16, leading_ones);
+    test_integer_unary!(run_test, i32, leading_ones);
+    test_integer_unary!(run_test, i64, leading_ones);
+    test_integer_unary!(run_test, i128, leading_ones);
+
+    test_integer_unary!(run_test, u8, leading_ones);
+    test_integer_unary!(run_test, u16, leading_ones);
+    test_integer_unary!(run_test, u32, leading_ones);
+    test_integer_unary!(run_test, u64, leading_ones);
+    test_integer_unary!(run_test, u128, leading_ones);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, leading_ones, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, leading_ones, exhaustive);
+}
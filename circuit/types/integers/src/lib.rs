@@ -21,29 +21,107 @@ mod helpers;
 pub mod abs_checked;
 pub mod abs_wrapped;
 pub mod add_checked;
+pub mod add_many;
+pub mod add_mod;
+pub mod add_saturating;
 pub mod add_wrapped;
 pub mod and;
+pub mod assert_compare;
+pub mod assert_in_range;
+pub mod bit_frequency;
+pub mod cast_checked;
+pub mod cast_saturating;
+pub mod cast_wrapped;
+pub mod choose;
+pub mod clamp;
+pub mod clmul;
 pub mod compare;
+pub mod concat;
+pub mod conditional_swap;
+pub mod count_ones;
+pub mod count_zeros;
+pub mod div_ceil;
 pub mod div_checked;
+pub mod div_euclidean;
 pub mod div_wrapped;
+pub mod dot_product;
+pub mod enforce_weight;
 pub mod equal;
+pub mod eval_horner;
+pub mod extend;
+pub mod extract_bits;
+pub mod funnel_shift;
+pub mod gcd;
+pub mod gray_code;
+pub mod hamming_distance;
+pub mod highest_set_bit;
+pub mod ilog;
+pub mod ilog2;
+pub mod interleave;
+pub mod inv_mod;
+pub mod is_even_odd;
+pub mod is_power_of_two;
+pub mod is_sign;
+pub mod isqrt;
+pub mod leading_ones;
+pub mod leading_zeros;
+pub mod majority;
+pub mod map_slice;
+pub mod midpoint;
+pub mod min_max;
 pub mod modulo;
+pub mod monus;
 pub mod mul_checked;
+pub mod mul_mod;
+pub mod mul_saturating;
 pub mod mul_wrapped;
+pub mod nand;
 pub mod neg;
+pub mod negate_if;
+pub mod nor;
 pub mod not;
 pub mod or;
+pub mod overflowing_add;
+pub mod overflowing_mul;
+pub mod overflowing_sub;
+pub mod pack_field;
+pub mod parity;
 pub mod pow_checked;
+pub mod pow_mod;
+pub mod pow_saturating;
 pub mod pow_wrapped;
+pub mod power_of_two;
+pub mod prefix_sum;
 pub mod rem_checked;
+pub mod rem_euclidean;
 pub mod rem_wrapped;
+pub mod repeat_byte;
+pub mod reverse_bits;
+pub mod rotate_left;
+pub mod rotate_right;
+pub mod sbox_lookup;
+pub mod select_bits;
+pub mod select_index;
+pub mod shl_barrel;
 pub mod shl_checked;
+pub mod shl_saturating;
 pub mod shl_wrapped;
+pub mod shr_barrel;
 pub mod shr_checked;
+pub mod shr_with_remainder;
 pub mod shr_wrapped;
+pub mod signum;
+pub mod split;
 pub mod sub_checked;
+pub mod sub_saturating;
 pub mod sub_wrapped;
+pub mod swap_bytes;
 pub mod ternary;
+pub mod trailing_ones;
+pub mod trailing_zeros;
+pub mod truncate_bits;
+pub mod try_pow;
+pub mod xnor;
 pub mod xor;
 
 pub type I8<E> = Integer<E, i8>;
@@ -77,6 +155,10 @@ use snarkvm_circuit_types_scalar::Scalar;
 
 use core::marker::PhantomData;
 
+/// A circuit integer of type `I`.
+///
+/// The arithmetic operators (`+`, `-`, `*`, and their assignment forms) use checked arithmetic, i.e. they halt
+/// (or are unsatisfiable) on overflow. Use the `*_wrapped` methods (e.g. `add_wrapped`) for wrapping semantics.
 #[derive(Clone)]
 pub struct Integer<E: Environment, I: IntegerType> {
     bits_le: Vec<Boolean<E>>,
@@ -96,6 +178,26 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
     pub fn cast_as_dual(self) -> Integer<E, I::Dual> {
         Integer::<E, I::Dual> { bits_le: self.bits_le, phantom: Default::default() }
     }
+
+    /// Returns the bit at the given little-endian index, without adding any constraints.
+    /// This halts if the index is not less than `I::BITS`.
+    pub fn get_bit(&self, index: usize) -> Boolean<E> {
+        match self.bits_le.get(index) {
+            Some(bit) => bit.clone(),
+            None => E::halt(format!("Attempted to get bit {index} of a {}-bit integer", I::BITS)),
+        }
+    }
+
+    /// Returns a copy of the integer with the bit at the given little-endian index replaced by `value`,
+    /// without adding any constraints. This halts if the index is not less than `I::BITS`.
+    pub fn set_bit(&self, index: usize, value: &Boolean<E>) -> Self {
+        if index >= I::BITS as usize {
+            E::halt(format!("Attempted to set bit {index} of a {}-bit integer", I::BITS))
+        }
+        let mut bits_le = self.bits_le.clone();
+        bits_le[index] = value.clone();
+        Integer { bits_le, phantom: Default::default() }
+    }
 }
 
 #[cfg(console)]
@@ -114,6 +216,19 @@ impl<E: Environment, I: IntegerType> Inject for Integer<E, I> {
     }
 }
 
+#[cfg(console)]
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes a new public integer, i.e. `Integer::new(Mode::Public, value)`.
+    pub fn public(value: console::Integer<E::Network, I>) -> Self {
+        Self::new(Mode::Public, value)
+    }
+
+    /// Initializes a new private integer, i.e. `Integer::new(Mode::Private, value)`.
+    pub fn private(value: console::Integer<E::Network, I>) -> Self {
+        Self::new(Mode::Private, value)
+    }
+}
+
 #[cfg(console)]
 impl<E: Environment, I: IntegerType> Eject for Integer<E, I> {
     type Primitive = console::Integer<E::Network, I>;
@@ -177,6 +292,15 @@ impl<E: Environment, I: IntegerType> TypeName for Integer<E, I> {
     }
 }
 
+#[cfg(console)]
+impl<E: Environment, I: IntegerType> From<I> for Integer<E, I> {
+    /// Initializes a new constant integer from a primitive value.
+    /// To initialize a public or private integer, use `Integer::new` instead.
+    fn from(value: I) -> Self {
+        Self::constant(console::Integer::new(value))
+    }
+}
+
 #[cfg(console)]
 impl<E: Environment, I: IntegerType> Debug for Integer<E, I> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -191,6 +315,27 @@ impl<E: Environment, I: IntegerType> Display for Integer<E, I> {
     }
 }
 
+#[cfg(console)]
+impl<E: Environment, I: IntegerType> Eq for Integer<E, I> {}
+
+#[cfg(console)]
+impl<E: Environment, I: IntegerType> PartialEq for Integer<E, I> {
+    /// Returns `true` if the integers have the same value and mode.
+    /// Note: This compares the ejected integers, not the underlying variables in the circuit.
+    fn eq(&self, other: &Self) -> bool {
+        self.eject() == other.eject()
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, I: IntegerType> core::hash::Hash for Integer<E, I> {
+    /// Hashes the value and mode of the integer, consistent with `PartialEq`.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.eject_value().hash(state);
+        self.eject_mode().hash(state);
+    }
+}
+
 impl<E: Environment, I: IntegerType> From<Integer<E, I>> for LinearCombination<E::BaseField> {
     fn from(integer: Integer<E, I>) -> Self {
         From::from(&integer)
@@ -215,6 +360,8 @@ mod tests {
     use super::*;
     use snarkvm_circuit_environment::Circuit;
 
+    use core::panic::RefUnwindSafe;
+
     const ITERATIONS: u64 = 100;
 
     fn check_new<I: IntegerType>(
@@ -264,18 +411,203 @@ mod tests {
 
     fn check_display<I: IntegerType>() {
         // Constant
-        let candidate = Integer::<Circuit, I>::new(Mode::Constant, console::Integer::one() + console::Integer::one());
+        let candidate = Integer::<Circuit, I>::constant(console::Integer::one() + console::Integer::one());
         assert_eq!(format!("2{}.constant", I::type_name()), format!("{candidate}"));
 
         // Public
-        let candidate = Integer::<Circuit, I>::new(Mode::Public, console::Integer::one() + console::Integer::one());
+        let candidate = Integer::<Circuit, I>::public(console::Integer::one() + console::Integer::one());
         assert_eq!(format!("2{}.public", I::type_name()), format!("{candidate}"));
 
         // Private
-        let candidate = Integer::<Circuit, I>::new(Mode::Private, console::Integer::one() + console::Integer::one());
+        let candidate = Integer::<Circuit, I>::private(console::Integer::one() + console::Integer::one());
         assert_eq!(format!("2{}.private", I::type_name()), format!("{candidate}"));
     }
 
+    fn check_mode_constructors<I: IntegerType>(rng: &mut TestRng) {
+        for _ in 0..ITERATIONS {
+            let expected = Uniform::rand(rng);
+
+            // Ensure each constructor matches `new` with the corresponding mode.
+            let candidate = Integer::<Circuit, I>::constant(expected);
+            assert_eq!((Mode::Constant, expected), candidate.eject());
+            let candidate = Integer::<Circuit, I>::public(expected);
+            assert_eq!((Mode::Public, expected), candidate.eject());
+            let candidate = Integer::<Circuit, I>::private(expected);
+            assert_eq!((Mode::Private, expected), candidate.eject());
+        }
+        Circuit::reset();
+    }
+
+    fn check_from_primitive<I: IntegerType>(rng: &mut TestRng) {
+        for _ in 0..ITERATIONS {
+            let expected: I = Uniform::rand(rng);
+
+            // Ensure the conversion produces a constant with the same value.
+            let candidate: Integer<Circuit, I> = expected.into();
+            assert_eq!(Mode::Constant, candidate.eject_mode());
+            assert_eq!(expected, *candidate.eject_value());
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_primitive() {
+        let mut rng = TestRng::default();
+
+        check_from_primitive::<u8>(&mut rng);
+        check_from_primitive::<i8>(&mut rng);
+        check_from_primitive::<u16>(&mut rng);
+        check_from_primitive::<i16>(&mut rng);
+        check_from_primitive::<u32>(&mut rng);
+        check_from_primitive::<i32>(&mut rng);
+        check_from_primitive::<u64>(&mut rng);
+        check_from_primitive::<i64>(&mut rng);
+        check_from_primitive::<u128>(&mut rng);
+        check_from_primitive::<i128>(&mut rng);
+    }
+
+    fn check_hash_and_eq<I: IntegerType>(rng: &mut TestRng) {
+        use std::collections::HashSet;
+
+        for _ in 0..ITERATIONS {
+            let first: console::Integer<_, I> = Uniform::rand(rng);
+            let second: console::Integer<_, I> = Uniform::rand(rng);
+            if first == second {
+                continue;
+            }
+
+            let mut set = HashSet::new();
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Insert the same value and mode twice, so that the duplicate collapses.
+                set.insert(Integer::<Circuit, I>::new(mode, first));
+                set.insert(Integer::<Circuit, I>::new(mode, first));
+                set.insert(Integer::<Circuit, I>::new(mode, second));
+            }
+            // Ensure distinct values and distinct modes remain distinct.
+            assert_eq!(6, set.len());
+
+            // Ensure equality depends on both the value and the mode.
+            assert_eq!(Integer::<Circuit, I>::private(first), Integer::private(first));
+            assert_ne!(Integer::<Circuit, I>::private(first), Integer::public(first));
+            assert_ne!(Integer::<Circuit, I>::private(first), Integer::private(second));
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_and_eq() {
+        let mut rng = TestRng::default();
+
+        check_hash_and_eq::<u8>(&mut rng);
+        check_hash_and_eq::<i8>(&mut rng);
+        check_hash_and_eq::<u16>(&mut rng);
+        check_hash_and_eq::<i16>(&mut rng);
+        check_hash_and_eq::<u32>(&mut rng);
+        check_hash_and_eq::<i32>(&mut rng);
+        check_hash_and_eq::<u64>(&mut rng);
+        check_hash_and_eq::<i64>(&mut rng);
+        check_hash_and_eq::<u128>(&mut rng);
+        check_hash_and_eq::<i128>(&mut rng);
+    }
+
+    fn check_get_and_set_bit<I: IntegerType + RefUnwindSafe>(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            let expected: console::Integer<_, I> = Uniform::rand(rng);
+            let candidate = Integer::<Circuit, I>::new(mode, expected);
+            let expected_bits_le = expected.to_bits_le();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                for (index, expected_bit) in expected_bits_le.iter().enumerate() {
+                    // Ensure `get_bit` returns the bit at the given index.
+                    assert_eq!(*expected_bit, candidate.get_bit(index).eject_value());
+
+                    // Ensure `set_bit` replaces only the bit at the given index, and round-trips with `get_bit`.
+                    for value in [true, false] {
+                        let updated = candidate.set_bit(index, &Boolean::constant(value));
+                        assert_eq!(value, updated.get_bit(index).eject_value());
+
+                        let mut expected_updated_bits_le = expected_bits_le.clone();
+                        expected_updated_bits_le[index] = value;
+                        assert_eq!(expected_updated_bits_le, updated.to_bits_le().eject_value());
+                    }
+                }
+                assert_scope!(0, 0, 0, 0);
+            });
+        }
+
+        // Ensure an out-of-range index halts.
+        let candidate = Integer::<Circuit, I>::new(mode, Uniform::rand(rng));
+        let result = std::panic::catch_unwind(|| candidate.get_bit(I::BITS as usize));
+        assert!(result.is_err());
+        let result = std::panic::catch_unwind(|| candidate.set_bit(I::BITS as usize, &Boolean::constant(true)));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_get_and_set_bit() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_get_and_set_bit::<u8>(mode, &mut rng);
+            check_get_and_set_bit::<i8>(mode, &mut rng);
+            check_get_and_set_bit::<u16>(mode, &mut rng);
+            check_get_and_set_bit::<i16>(mode, &mut rng);
+            check_get_and_set_bit::<u32>(mode, &mut rng);
+            check_get_and_set_bit::<i32>(mode, &mut rng);
+            check_get_and_set_bit::<u64>(mode, &mut rng);
+            check_get_and_set_bit::<i64>(mode, &mut rng);
+            check_get_and_set_bit::<u128>(mode, &mut rng);
+            check_get_and_set_bit::<i128>(mode, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_mode_constructors() {
+        let mut rng = TestRng::default();
+
+        check_mode_constructors::<u8>(&mut rng);
+        check_mode_constructors::<i8>(&mut rng);
+        check_mode_constructors::<u16>(&mut rng);
+        check_mode_constructors::<i16>(&mut rng);
+        check_mode_constructors::<u32>(&mut rng);
+        check_mode_constructors::<i32>(&mut rng);
+        check_mode_constructors::<u64>(&mut rng);
+        check_mode_constructors::<i64>(&mut rng);
+        check_mode_constructors::<u128>(&mut rng);
+        check_mode_constructors::<i128>(&mut rng);
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        let (_, candidate) = Integer::<Circuit, u8>::parse("0xFFu8.public").unwrap();
+        assert_eq!((Mode::Public, console::Integer::new(255)), candidate.eject());
+
+        let (_, candidate) = Integer::<Circuit, i8>::parse("-0x1Fi8").unwrap();
+        assert_eq!((Mode::Constant, console::Integer::new(-31)), candidate.eject());
+
+        let (_, candidate) = Integer::<Circuit, u32>::parse("0xdead_beefu32.private").unwrap();
+        assert_eq!((Mode::Private, console::Integer::new(0xdead_beef)), candidate.eject());
+
+        assert!(Integer::<Circuit, u8>::parse("0x100u8.public").is_err());
+        assert!(Integer::<Circuit, i8>::parse("0x80i8").is_err());
+    }
+
+    #[test]
+    fn test_parse_binary_and_octal() {
+        let (_, candidate) = Integer::<Circuit, u8>::parse("0b1010u8.public").unwrap();
+        assert_eq!((Mode::Public, console::Integer::new(10)), candidate.eject());
+
+        let (_, candidate) = Integer::<Circuit, u8>::parse("0o17u8.private").unwrap();
+        assert_eq!((Mode::Private, console::Integer::new(15)), candidate.eject());
+
+        let (_, candidate) = Integer::<Circuit, i16>::parse("-0b1_0000i16").unwrap();
+        assert_eq!((Mode::Constant, console::Integer::new(-16)), candidate.eject());
+
+        assert!(Integer::<Circuit, u8>::parse("0b1_0000_0000u8.public").is_err());
+        assert!(Integer::<Circuit, u8>::parse("0o400u8").is_err());
+    }
+
     // u8
 
     #[test]
@@ -529,7 +861,36 @@ mod tests {
 
 #[cfg(test)]
 mod test_utilities {
-    use core::panic::UnwindSafe;
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::{RefUnwindSafe, UnwindSafe};
+
+    impl<E: Environment, I: IntegerType> Integer<E, I> {
+        /// Initializes a new integer with the given mode, sampled uniformly from the range `[min, max]`.
+        pub fn rand_bounded<R: Rng + ?Sized>(mode: Mode, rng: &mut R, min: I, max: I) -> Self {
+            // Ensure the range is well-formed.
+            assert!(min <= max, "Invalid range: the minimum ({min}) exceeds the maximum ({max})");
+
+            // Embed the bounds into a `u128`, such that the offset between them is preserved (modulo 2^128).
+            let embed = |value: I| match I::is_signed() {
+                true => value.to_i128().unwrap() as u128,
+                false => value.to_u128().unwrap(),
+            };
+            let (min, max) = (embed(min), embed(max));
+
+            // Sample an offset uniformly from `[0, max - min]`, and add it to the minimum.
+            let value = min.wrapping_add(rng.gen_range(0..=max.wrapping_sub(min)));
+
+            // Recover the primitive from its embedding.
+            let value = match I::is_signed() {
+                true => I::from(value as i128).unwrap(),
+                false => I::from(value).unwrap(),
+            };
+            Integer::new(mode, console::Integer::new(value))
+        }
+    }
 
     /// A generic template for an integer test case.
     #[macro_export]
@@ -754,4 +1115,62 @@ mod test_utilities {
         let result = std::panic::catch_unwind(|| operation(input));
         assert!(result.is_err());
     }
+
+    /// A binary operator on integers, applied to borrowed operands.
+    pub type BinaryOperator<I> = fn(&Integer<Circuit, I>, &Integer<Circuit, I>) -> Integer<Circuit, I>;
+
+    /// Returns each form of a binary operator as a `[BinaryOperator<I>; 6]`, i.e. for `binary_operator_forms!(+, +=)`:
+    /// `&a + &b`, `&a + b`, `a + &b`, `a + b`, `a += &b`, and `a += b`.
+    #[macro_export]
+    macro_rules! binary_operator_forms {
+        ($operator:tt, $assign:tt) => {
+            [
+                |a, b| a $operator b,
+                |a, b| a $operator b.clone(),
+                |a, b| a.clone() $operator b,
+                |a, b| a.clone() $operator b.clone(),
+                |a, b| {
+                    let mut output = a.clone();
+                    output $assign b;
+                    output
+                },
+                |a, b| {
+                    let mut output = a.clone();
+                    output $assign b.clone();
+                    output
+                },
+            ]
+        };
+    }
+
+    /// Checks each of the given forms of a binary operator on `a` and `b`.
+    ///
+    /// If `expected` is `None`, each form must halt if both operands are constant, and must not satisfy the circuit
+    /// otherwise. Otherwise, each form must return `expected`, and `check` is invoked on the result within its scope.
+    pub fn check_binary_operator_forms<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        forms: &[BinaryOperator<I>],
+        a: &Integer<Circuit, I>,
+        b: &Integer<Circuit, I>,
+        expected: Option<console::Integer<<Circuit as Environment>::Network, I>>,
+        check: impl Fn(&Integer<Circuit, I>),
+    ) {
+        for (i, form) in forms.iter().enumerate() {
+            match expected {
+                Some(expected) => Circuit::scope(format!("{name} (form {i})"), || {
+                    let candidate = form(a, b);
+                    assert_eq!(expected, candidate.eject_value());
+                    check(&candidate);
+                }),
+                None => match a.is_constant() && b.is_constant() {
+                    true => check_operation_halts(a, b, *form),
+                    false => Circuit::scope(format!("{name} (form {i})"), || {
+                        let _candidate = form(a, b);
+                        assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                    }),
+                },
+            }
+            Circuit::reset();
+        }
+    }
 }
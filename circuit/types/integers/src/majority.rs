@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the bitwise majority of `a`, `b`, and `c`, i.e. `(a & b) | (a & c) | (b & c)`.
+    pub fn majority(a: &Self, b: &Self, c: &Self) -> Self {
+        (a & b) | (a & c) | (b & c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_majority<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        third: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+        mode_c: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let c = Integer::<Circuit, I>::new(mode_c, third);
+
+        // Compute the expected value, where each bit is set if at least two of the inputs have the bit set.
+        let mut expected = I::zero();
+        for i in 0..I::BITS as usize {
+            let count = [*first, *second, *third].iter().filter(|value| (**value >> i) & I::one() == I::one()).count();
+            if count >= 2 {
+                expected = expected | (I::one() << i);
+            }
+        }
+
+        Circuit::scope(name, || {
+            let candidate = Integer::majority(&a, &b, &c);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_scope!();
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let third = Uniform::rand(&mut rng);
+
+            let name = format!("Majority: ({mode_a}, {mode_b}, {mode_c}) {i}");
+            check_majority::<I>(&name, first, second, third, mode_a, mode_b, mode_c);
+
+            // Check that the majority of two equal inputs is that input.
+            let name = format!("Majority Identity: ({mode_a}, {mode_b}, {mode_c}) {i}");
+            check_majority::<I>(&name, first, first, third, mode_a, mode_b, mode_c);
+            check_majority::<I>(&name, first, second, first, mode_a, mode_b, mode_c);
+            check_majority::<I>(&name, second, third, third, mode_a, mode_b, mode_c);
+        }
+
+        // Check the zero and bounds cases.
+        let (zero, max, min) = (console::Integer::zero(), console::Integer::MAX, console::Integer::MIN);
+        check_majority::<I>("Majority: (0, MAX, MIN)", zero, max, min, mode_a, mode_b, mode_c);
+        check_majority::<I>("Majority: (MAX, MIN, MAX)", max, min, max, mode_a, mode_b, mode_c);
+    }
+
+    test_integer_ternary!(run_test, i8, majority, with, and);
+    test_integer_ternary!(run_test, i16, majority, with, and);
+    test_integer_ternary!(run_test, i32, majority, with, and);
+    test_integer_ternary!(run_test, i64, majority, with, and);
+    test_integer_ternary!(run_test, i128, majority, with, and);
+
+    test_integer_ternary!(run_test, u8, majority, with, and);
+    test_integer_ternary!(run_test, u16, majority, with, and);
+    test_integer_ternary!(run_test, u32, majority, with, and);
+    test_integer_ternary!(run_test, u64, majority, with, and);
+    test_integer_ternary!(run_test, u128, majority, with, and);
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the result of applying `f` to each integer in `values`.
+    pub fn map_slice(values: &[Self], f: impl Fn(&Self) -> Self) -> Vec<Self> {
+        values.iter().map(f).collect()
+    }
+
+    /// Returns the result of applying `f` to each pair of integers in `first` and `second`.
+    pub fn zip_map(first: &[Self], second: &[Self], f: impl Fn(&Self, &Self) -> Self) -> Vec<Self> {
+        // Ensure the length matches.
+        if first.len() != second.len() {
+            E::halt(format!("Mismatching length of integers ({} != {})", first.len(), second.len()))
+        }
+        first.iter().zip_eq(second).map(|(a, b)| f(a, b)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+    const NUM_VALUES: usize = 16;
+
+    /// Samples a list of random integers, excluding `Integer::MIN` so that they may be negated.
+    fn sample_values<I: IntegerType>(mode: Mode, rng: &mut TestRng) -> Vec<Integer<Circuit, I>> {
+        (0..NUM_VALUES)
+            .map(|_| match Uniform::rand(rng) {
+                value if value == console::Integer::MIN => Integer::new(mode, console::Integer::zero()),
+                value => Integer::new(mode, value),
+            })
+            .collect()
+    }
+
+    fn check_map_slice<I: IntegerType>(
+        name: &str,
+        values: &[Integer<Circuit, I>],
+        f: impl Fn(&Integer<Circuit, I>) -> Integer<Circuit, I>,
+    ) {
+        // Compute the expected values with a manual loop.
+        let mut expected = Vec::with_capacity(values.len());
+        for value in values {
+            expected.push(f(value).eject_value());
+        }
+
+        Circuit::scope(name, || {
+            let candidate = Integer::map_slice(values, &f);
+            assert_eq!(expected, candidate.iter().map(|value| value.eject_value()).collect::<Vec<_>>());
+            assert_scope!();
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = sample_values::<I>(mode_a, &mut rng);
+            let second = sample_values::<I>(mode_b, &mut rng);
+
+            // Check `neg` for signed integers.
+            if I::is_signed() {
+                check_map_slice(&format!("MapSlice: neg {mode_a} {i}"), &first, |value| -value);
+            }
+
+            // Check `double`.
+            check_map_slice(&format!("MapSlice: double {mode_a} {i}"), &first, |value| value.add_wrapped(value));
+
+            // Check `add_wrapped` over two slices.
+            let mut expected = Vec::with_capacity(NUM_VALUES);
+            for (a, b) in first.iter().zip(second.iter()) {
+                expected.push(a.add_wrapped(b).eject_value());
+            }
+
+            Circuit::scope(format!("ZipMap: add_wrapped {mode_a} {mode_b} {i}"), || {
+                let candidate = Integer::zip_map(&first, &second, |a, b| a.add_wrapped(b));
+                assert_eq!(expected, candidate.iter().map(|value| value.eject_value()).collect::<Vec<_>>());
+                assert_scope!();
+            });
+            Circuit::reset();
+        }
+
+        // Check that mismatching lengths halt.
+        let first = sample_values::<I>(mode_a, &mut rng);
+        let second = sample_values::<I>(mode_b, &mut rng)[1..].to_vec();
+        check_operation_halts(first, second, |first: Vec<Integer<Circuit, I>>, second: Vec<Integer<Circuit, I>>| {
+            Integer::zip_map(&first, &second, |a, b| a.add_wrapped(b))
+        });
+    }
+
+    test_integer_binary!(run_test, i8, map_slice);
+    test_integer_binary!(run_test, i16, map_slice);
+    test_integer_binary!(run_test, i32, map_slice);
+    test_integer_binary!(run_test, i64, map_slice);
+    test_integer_binary!(run_test, i128, map_slice);
+
+    test_integer_binary!(run_test, u8, map_slice);
+    test_integer_binary!(run_test, u16, map_slice);
+    test_integer_binary!(run_test, u32, map_slice);
+    test_integer_binary!(run_test, u64, map_slice);
+    test_integer_binary!(run_test, u128, map_slice);
+}
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Midpoint<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `(self + other) / 2`, rounded towards negative infinity, without overflowing.
+    /// This operation is only defined for unsigned integers.
+    #[inline]
+    fn midpoint(&self, other: &Integer<E, I>) -> Self::Output {
+        // Ensure the integer type is unsigned.
+        // Note: Signed midpoints round towards zero, which requires additional sign handling, and are not supported.
+        if I::is_signed() {
+            E::halt("The midpoint is only defined for unsigned integers")
+        }
+
+        if self.is_constant() && other.is_constant() {
+            // Compute the midpoint as `(a & b) + ((a ^ b) >> 1)`, and return the new constant.
+            witness!(|self, other| console::Integer::new((*self & *other) + ((*self ^ *other) >> 1usize)))
+        } else {
+            // Instead of computing `(a & b) + ((a ^ b) >> 1)` with `and`, `xor`, and `shr_wrapped`, which costs
+            // roughly `3 * I::BITS` constraints (for the AND, the XOR, and the addition), the integers are converted
+            // into field elements and summed, so the carry bit is retained, at a cost of `I::BITS + 2` constraints.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            let sum = self.to_field() + other.to_field();
+
+            // Extract the integer bits from the field element, with a carry bit.
+            let mut bits_le = sum.to_lower_bits_le(I::BITS as usize + 1);
+            // Drop the least significant bit, which halves the sum and rounds towards negative infinity.
+            bits_le.remove(0);
+
+            // Return the midpoint of `self` and `other`.
+            Integer { bits_le, phantom: Default::default() }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Midpoint<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 2),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Midpoint<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_midpoint<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::new(mode_b, second);
+        // Compute the expected midpoint on the primitives, rounding towards negative infinity.
+        let expected = (*first & *second) + ((*first ^ *second) >> 1usize);
+        Circuit::scope(name, || {
+            let candidate = a.midpoint(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(Midpoint(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Midpoint(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Midpoint: {mode_a} {mode_b} {i}");
+            check_midpoint::<I>(&name, first, second, mode_a, mode_b);
+            check_midpoint::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+
+            // Check values near `MAX`, where the naive sum overflows.
+            let name = format!("Midpoint Near MAX: {mode_a} {mode_b} {i}");
+            let first = console::Integer::new(I::MAX.wrapping_sub(&(*first >> (I::BITS as usize / 2))));
+            check_midpoint::<I>(&name, first, console::Integer::MAX, mode_a, mode_b);
+        }
+
+        // Check the corner cases.
+        let one = console::Integer::one();
+        check_midpoint::<I>("MAX, MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_midpoint::<I>("MAX, MAX - 1", console::Integer::MAX, console::Integer::MAX - one, mode_a, mode_b);
+        check_midpoint::<I>("MAX, 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_midpoint::<I>("MAX, 1", console::Integer::MAX, one, mode_a, mode_b);
+        check_midpoint::<I>("0, 0", console::Integer::zero(), console::Integer::zero(), mode_a, mode_b);
+        check_midpoint::<I>("0, 1", console::Integer::zero(), one, mode_a, mode_b);
+    }
+
+    fn run_signed_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let a = Integer::<Circuit, I>::new(mode_a, console::Integer::one());
+        let b = Integer::<Circuit, I>::new(mode_b, console::Integer::one());
+        check_operation_halts(&a, &b, Integer::midpoint);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("Midpoint: ({first}, {second})");
+                check_midpoint::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, u8, midpoint);
+    test_integer_binary!(run_test, u16, midpoint);
+    test_integer_binary!(run_test, u32, midpoint);
+    test_integer_binary!(run_test, u64, midpoint);
+    test_integer_binary!(run_test, u128, midpoint);
+
+    test_integer_binary!(run_signed_test, i8, midpoint_halts);
+    test_integer_binary!(run_signed_test, i64, midpoint_halts);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, midpoint, exhaustive);
+}
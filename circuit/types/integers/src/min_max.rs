@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Max<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the larger of `self` and `other`.
+    fn max(&self, other: &Self) -> Self::Output {
+        Self::ternary(&self.is_less_than(other), other, self)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Min<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the smaller of `self` and `other`.
+    fn min(&self, other: &Self) -> Self::Output {
+        Self::ternary(&self.is_less_than(other), self, other)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Max<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match I::is_signed() {
+            true => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(1, 0, 0, 0),
+                (Mode::Constant, _) | (_, Mode::Constant) => Count::is(I::BITS, 0, 2 * I::BITS + 2, 2 * I::BITS + 3),
+                (_, _) => Count::is(I::BITS, 0, 2 * I::BITS + 4, 2 * I::BITS + 5),
+            },
+            false => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(1, 0, 0, 0),
+                (_, _) => Count::is(I::BITS, 0, 2 * I::BITS + 1, 2 * I::BITS + 2),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Max<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Min<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        <Self as Metrics<dyn Max<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Min<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        <Self as OutputMode<dyn Max<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_min_max<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        // Check the `max` operation.
+        let expected = core::cmp::max(*first, *second);
+        Circuit::scope(name, || {
+            let candidate = a.max(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(Max(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Max(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+
+        // Check the `min` operation.
+        let expected = core::cmp::min(*first, *second);
+        Circuit::scope(name, || {
+            let candidate = a.min(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(Min(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Min(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("MinMax: {mode_a} {mode_b} {i}");
+            check_min_max::<I>(&name, first, second, mode_a, mode_b);
+            check_min_max::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+            check_min_max::<I>(&name, first, first, mode_a, mode_b);
+        }
+
+        // Check the corner cases.
+        check_min_max::<I>("MAX, MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+        check_min_max::<I>("MIN, MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        check_min_max::<I>("0, 1", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+
+        // Check the sign boundary for signed integers.
+        if I::is_signed() {
+            check_min_max::<I>("-1, 0", -console::Integer::one(), console::Integer::zero(), mode_a, mode_b);
+            check_min_max::<I>("-1, 1", -console::Integer::one(), console::Integer::one(), mode_a, mode_b);
+            check_min_max::<I>("MIN, -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("MinMax: ({first}, {second})");
+                check_min_max::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, min_max);
+    test_integer_binary!(run_test, i16, min_max);
+    test_integer_binary!(run_test, i32, min_max);
+    test_integer_binary!(run_test, i64, min_max);
+    test_integer_binary!(run_test, i128, min_max);
+
+    test_integer_binary!(run_test, u8, min_max);
+    test_integer_binary!(run_test, u16, min_max);
+    test_integer_binary!(run_test, u32, min_max);
+    test_integer_binary!(run_test, u64, min_max);
+    test_integer_binary!(run_test, u128, min_max);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, min_max, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, min_max, exhaustive);
+}
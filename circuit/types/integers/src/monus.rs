@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the truncated difference of `self` and `other`, i.e. `max(self - other, 0)`.
+    /// This operation is only defined for unsigned integers.
+    pub fn monus(&self, other: &Integer<E, I>) -> Self {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("Truncated subtraction is only defined for unsigned integers")
+        }
+
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the truncated difference and return the new constant.
+            witness!(|self, other| console::Integer::new((*self).saturating_sub(*other)))
+        } else {
+            // Compute the wrapped difference of `self` and `other`, as in `sub_wrapped`.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            let difference = self.to_field() + (!other).to_field() + Field::one();
+
+            // Extract the integer bits from the field element, with a carry bit.
+            let mut bits_le = difference.to_lower_bits_le(I::BITS as usize + 1);
+            // The carry bit is set if and only if `self` is greater than or equal to `other`.
+            let is_not_borrow = match bits_le.pop() {
+                Some(bit) => bit,
+                // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                None => E::halt("Malformed difference detected during truncated subtraction"),
+            };
+
+            // Return the difference if there is no borrow, and zero otherwise.
+            Integer { bits_le: bits_le.iter().map(|bit| bit & &is_not_borrow).collect(), phantom: Default::default() }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_monus<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let expected = (*first).saturating_sub(*second);
+
+        Circuit::scope(name, || {
+            let candidate = a.monus(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            match mode_a.is_constant() && mode_b.is_constant() {
+                true => assert_scope!(I::BITS, 0, 0, 0),
+                false => assert_scope!(0, 0, 2 * I::BITS + 1, 2 * I::BITS + 2),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Monus: {mode_a} - {mode_b} {i}");
+            check_monus::<I>(&name, first, second, mode_a, mode_b);
+            check_monus::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+        }
+
+        // Check the equal and bounds cases.
+        check_monus::<I>("MAX - MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_monus::<I>("MAX - 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_monus::<I>("0 - MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        check_monus::<I>("0 - 1", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("Monus: ({first} - {second})");
+                check_monus::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, u8, monus);
+    test_integer_binary!(run_test, u16, monus);
+    test_integer_binary!(run_test, u32, monus);
+    test_integer_binary!(run_test, u64, monus);
+    test_integer_binary!(run_test, u128, monus);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, monus, exhaustive);
+}
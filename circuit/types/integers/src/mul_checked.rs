@@ -14,6 +14,8 @@
 
 use super::*;
 
+use core::iter::Product;
+
 impl<E: Environment, I: IntegerType> Mul<Integer<E, I>> for Integer<E, I> {
     type Output = Self;
 
@@ -65,7 +67,7 @@ impl<E: Environment, I: IntegerType> Metrics<dyn Mul<Integer<E, I>, Output = Int
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
-        <Self as Metrics<dyn DivChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
+        <Self as Metrics<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
     }
 }
 
@@ -73,7 +75,25 @@ impl<E: Environment, I: IntegerType> OutputMode<dyn Mul<Integer<E, I>, Output =
     type Case = (Mode, Mode);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        <Self as OutputMode<dyn DivChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
+        <Self as OutputMode<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Product<Integer<E, I>> for Integer<E, I> {
+    /// Returns the checked product of the integers, or `one` if the iterator is empty.
+    /// Note that this halts (or is unsatisfiable) on overflow, in the same manner as `mul_checked`.
+    #[inline]
+    fn product<Iter: Iterator<Item = Integer<E, I>>>(iter: Iter) -> Self {
+        iter.fold(Integer::one(), |a, b| a * b)
+    }
+}
+
+impl<'a, E: Environment, I: IntegerType> Product<&'a Integer<E, I>> for Integer<E, I> {
+    /// Returns the checked product of the integers, or `one` if the iterator is empty.
+    /// Note that this halts (or is unsatisfiable) on overflow, in the same manner as `mul_checked`.
+    #[inline]
+    fn product<Iter: Iterator<Item = &'a Integer<E, I>>>(iter: Iter) -> Self {
+        iter.fold(Integer::one(), |a, b| a * b)
     }
 }
 
@@ -374,6 +394,130 @@ mod tests {
         }
     }
 
+    fn run_operator_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let forms: [BinaryOperator<I>; 6] = binary_operator_forms!(*, *=);
+        let check = |candidate: &Integer<Circuit, I>| {
+            assert_count!(Mul(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Mul(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        };
+
+        let mut samples: Vec<(console::Integer<_, I>, console::Integer<_, I>)> =
+            (0..ITERATIONS).map(|_| (Uniform::rand(&mut rng), Uniform::rand(&mut rng))).collect();
+
+        // Include operands that overflow, which the operator must reject.
+        samples.push((console::Integer::MAX, console::Integer::one() + console::Integer::one()));
+        if I::is_signed() {
+            samples.push((console::Integer::MIN, -console::Integer::one()));
+        }
+
+        for (i, (first, second)) in samples.into_iter().enumerate() {
+            let a = Integer::<Circuit, I>::new(mode_a, first);
+            let b = Integer::<Circuit, I>::new(mode_b, second);
+            let expected = first.checked_mul(&second).map(console::Integer::new);
+            let name = format!("MulOperator: {mode_a} * {mode_b} {i}");
+            check_binary_operator_forms(&name, &forms, &a, &b, expected, check);
+        }
+    }
+
+    fn run_product_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for length in 0..4 {
+            // Sample operands small enough that their product cannot overflow.
+            let integers: Vec<Integer<Circuit, I>> =
+                (0..length).map(|_| Integer::rand_bounded(mode, &mut rng, I::zero(), I::from(3).unwrap())).collect();
+            let expected = integers.iter().fold(I::one(), |a, b| a * *b.eject_value());
+
+            Circuit::scope(format!("Product: {mode} {length}"), || {
+                let candidate: Integer<Circuit, I> = integers.iter().product();
+                assert_eq!(expected, *candidate.eject_value());
+                let candidate: Integer<Circuit, I> = integers.clone().into_iter().product();
+                assert_eq!(expected, *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+
+        // Ensure the empty product is a constant `one`.
+        let candidate: Integer<Circuit, I> = core::iter::empty::<Integer<Circuit, I>>().product();
+        assert_eq!((Mode::Constant, console::Integer::one()), candidate.eject());
+
+        // Ensure an overflowing product of constants halts.
+        let a = Integer::<Circuit, I>::constant(console::Integer::MAX);
+        let b = Integer::<Circuit, I>::constant(console::Integer::one() + console::Integer::one());
+        check_operation_halts(&a, &b, |a, b| [a, b].into_iter().product::<Integer<Circuit, I>>());
+        Circuit::reset();
+    }
+
+    /// Multiply the integer bits of `this` and `that` with four half-width products, as a baseline for
+    /// `karatsuba_multiply`. Returns the product, the upper bits of `z1`, and `z2` as a field element.
+    fn schoolbook_multiply(
+        this: &Integer<Circuit, u128>,
+        that: &Integer<Circuit, u128>,
+    ) -> (Integer<Circuit, u128>, Vec<Boolean<Circuit>>, Field<Circuit>) {
+        let x_1 = Field::from_bits_le(&this.bits_le[64..]);
+        let x_0 = Field::from_bits_le(&this.bits_le[..64]);
+        let y_1 = Field::from_bits_le(&that.bits_le[64..]);
+        let y_0 = Field::from_bits_le(&that.bits_le[..64]);
+
+        let z_0 = &x_0 * &y_0;
+        let z_1 = (&x_1 * &y_0) + (&x_0 * &y_1);
+        let z_2 = &x_1 * &y_1;
+
+        let mut b_m_bits = vec![Boolean::constant(false); 64];
+        b_m_bits.push(Boolean::constant(true));
+
+        let b_m = Field::from_bits_le(&b_m_bits);
+        let z_0_plus_scaled_z_1 = &z_0 + (&z_1 * &b_m);
+
+        let bits_le = z_0_plus_scaled_z_1.to_lower_bits_le(128 + 64 + 1);
+        let (bits_le, carry) = bits_le.split_at(128);
+
+        (Integer::from_bits_le(bits_le), carry.to_vec(), z_2)
+    }
+
+    #[test]
+    fn test_karatsuba_multiply_u128() {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS as u32 {
+            // Sample operands whose widths sum to about 128 bits, so that only some of the products overflow.
+            let shift_a = (4 * i) % 128;
+            let shift_b = (128 - shift_a).saturating_sub(i % 3).min(127);
+            let a = Integer::<Circuit, u128>::rand_bounded(Mode::Private, &mut rng, 0, u128::MAX >> shift_a);
+            let b = Integer::<Circuit, u128>::rand_bounded(Mode::Private, &mut rng, 0, u128::MAX >> shift_b);
+
+            let karatsuba = Circuit::scope(format!("Karatsuba {i}"), || {
+                let (product, z_1_upper_bits, z_2) = Integer::karatsuba_multiply(&a, &b);
+                let (a, b) = (*a.eject_value(), *b.eject_value());
+
+                // Check the product against the schoolbook product, which overflows iff either carry term is nonzero.
+                assert_eq!(a.wrapping_mul(b), *product.eject_value());
+                let is_overflow = z_1_upper_bits.iter().any(|bit| bit.eject_value()) || !z_2.eject_value().is_zero();
+                assert_eq!(a.checked_mul(b).is_none(), is_overflow);
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+            });
+
+            let schoolbook = Circuit::scope(format!("Schoolbook {i}"), || {
+                let (product, z_1_upper_bits, z_2) = schoolbook_multiply(&a, &b);
+                let (a, b) = (*a.eject_value(), *b.eject_value());
+
+                assert_eq!(a.wrapping_mul(b), *product.eject_value());
+                let is_overflow = z_1_upper_bits.iter().any(|bit| bit.eject_value()) || !z_2.eject_value().is_zero();
+                assert_eq!(a.checked_mul(b).is_none(), is_overflow);
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+            });
+
+            // Karatsuba saves one of the four field multiplications, i.e. one private variable and one constraint.
+            assert_eq!((schoolbook.0 - 1, schoolbook.1 - 1), karatsuba);
+            Circuit::reset();
+        }
+    }
+
     fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
     where
         RangeInclusive<I>: Iterator<Item = I>,
@@ -401,6 +545,23 @@ mod tests {
     test_integer_binary!(run_test, u64, times);
     test_integer_binary!(run_test, u128, times);
 
+    test_integer_binary!(run_operator_test, i8, times_operator);
+    test_integer_binary!(run_operator_test, i64, times_operator);
+    test_integer_binary!(run_operator_test, u32, times_operator);
+    test_integer_binary!(run_operator_test, u128, times_operator);
+
+    test_integer_unary!(run_product_test, i8, product);
+    test_integer_unary!(run_product_test, i16, product);
+    test_integer_unary!(run_product_test, i32, product);
+    test_integer_unary!(run_product_test, i64, product);
+    test_integer_unary!(run_product_test, i128, product);
+
+    test_integer_unary!(run_product_test, u8, product);
+    test_integer_unary!(run_product_test, u16, product);
+    test_integer_unary!(run_product_test, u32, product);
+    test_integer_unary!(run_product_test, u64, product);
+    test_integer_unary!(run_product_test, u128, product);
+
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, times, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, times, exhaustive);
 }
@@ -0,0 +1,317 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> MulMod<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `(self * other) % modulus`, for a constant `modulus`.
+    /// This operation is only defined for unsigned integers.
+    ///
+    /// Note: This assumes that `self` and `other` are both less than `modulus`, so that the
+    /// quotient of the product fits in `I::BITS` bits. If the assumption does not hold,
+    /// the circuit may not be satisfied.
+    #[inline]
+    fn mul_mod(&self, other: &Integer<E, I>, modulus: &Integer<E, I>) -> Self::Output {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("Modular multiplication is only defined for unsigned integers")
+        }
+        // Ensure the modulus is a nonzero constant.
+        if !modulus.is_constant() {
+            E::halt("The modulus of a modular multiplication must be a constant")
+        }
+        if *modulus.eject_value() == I::zero() {
+            E::halt("The modulus of a modular multiplication must be nonzero")
+        }
+
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Ensure the operands are reduced, as in the non-constant case.
+            if self.eject_value() >= modulus.eject_value() || other.eject_value() >= modulus.eject_value() {
+                E::halt("The operands of a modular multiplication must be less than the modulus")
+            }
+            // Compute the reduced product and return the new constant.
+            witness!(|self, other, modulus| console::Integer::new(mul_mod(*self, *other, *modulus)))
+        } else {
+            // Witness the remainder of the product divided by the modulus.
+            let remainder = Integer::new(
+                Mode::Private,
+                console::Integer::new(mul_mod(*self.eject_value(), *other.eject_value(), *modulus.eject_value())),
+            );
+
+            // Witness the quotient of the product divided by the modulus, by dividing in the base field.
+            // Note: This is exact, as the product minus the remainder is a multiple of the modulus,
+            // and the quotient is less than `2^I::BITS`, which is less than the base field modulus.
+            let quotient_value = match modulus.to_field().eject_value().inverse() {
+                Ok(inverse) => {
+                    (self.to_field().eject_value() * other.to_field().eject_value()
+                        - remainder.to_field().eject_value())
+                        * inverse
+                }
+                // Note: `E::halt` should never be invoked as the modulus is nonzero in the base field.
+                Err(_) => E::halt("Failed to invert the modulus during modular multiplication"),
+            };
+            let quotient = Integer::from_bits_le(
+                &quotient_value.to_bits_le()[..I::BITS as usize]
+                    .iter()
+                    .map(|bit| Boolean::new(Mode::Private, *bit))
+                    .collect::<Vec<_>>(),
+            );
+
+            // Ensure that the remainder is less than the modulus, i.e. that `modulus - 1 - remainder` fits in
+            // `I::BITS` bits. As the modulus is a constant, this is cheaper than a general comparison.
+            (modulus.to_field() - Field::one() - remainder.to_field()).to_lower_bits_le(I::BITS as usize);
+
+            if 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
+                // Ensure that `self * other == quotient * modulus + remainder` holds in the base field.
+                // Note: This is sound, as neither side can wrap around the base field modulus.
+                E::assert_eq(
+                    self.to_field() * other.to_field(),
+                    quotient.to_field() * modulus.to_field() + remainder.to_field(),
+                );
+            } else {
+                // Ensure that `self * other == quotient * modulus + remainder` holds as integers.
+                self.assert_wide_product(other, &quotient, modulus, &remainder);
+            }
+
+            // Return the remainder.
+            remainder
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Enforces that `self * other == quotient * modulus + remainder` as integers, for a constant `modulus`.
+    /// This is used when the product may wrap around the base field modulus, by splitting each
+    /// operand into two limbs of `I::BITS / 2` bits, and checking the product one limb at a time.
+    fn assert_wide_product(&self, other: &Self, quotient: &Self, modulus: &Self, remainder: &Self) {
+        let num_half_bits = I::BITS as usize / 2;
+        let split = |integer: &Self| {
+            (
+                Field::from_bits_le(&integer.bits_le[..num_half_bits]),
+                Field::from_bits_le(&integer.bits_le[num_half_bits..]),
+            )
+        };
+        let (a_0, a_1) = split(self);
+        let (b_0, b_1) = split(other);
+        let (q_0, q_1) = split(quotient);
+        let (m_0, m_1) = split(modulus);
+
+        // Initialize the constant `2^num_half_bits`.
+        let base = (0..num_half_bits).fold(Field::<E>::one(), |power, _| power.double());
+
+        // Check the lower limb, where `a_0 * b_0 - q_0 * m_0 - remainder` is in `(-2 * base^2, base^2)`.
+        let lower = &a_0 * &b_0 - &q_0 * &m_0 - remainder.to_field();
+        let carry_0 = Self::witness_carry(&lower, &base, num_half_bits + 2);
+
+        // Check the middle limb, which is in `(-(2 * base + 1) * base, (2 * base + 1) * base)`.
+        let middle = &a_1 * &b_0 + &a_0 * &b_1 - &q_1 * &m_0 - &q_0 * &m_1 + carry_0;
+        let carry_1 = Self::witness_carry(&middle, &base, num_half_bits + 3);
+
+        // Check the upper limb, which must have no carry.
+        E::assert_eq(&a_1 * &b_1 + carry_1, &q_1 * &m_1);
+    }
+
+    /// Returns the carry `value / base`, enforcing that `value` is a multiple of `base`
+    /// and that the carry is in `[-2^(num_bits - 1), 2^(num_bits - 1))`.
+    fn witness_carry(value: &Field<E>, base: &Field<E>, num_bits: usize) -> Field<E> {
+        // Witness the carry, which is exact if `value` is a multiple of `base`.
+        let carry = match base.eject_value().inverse() {
+            Ok(inverse) => Field::new(Mode::Private, value.eject_value() * inverse),
+            // Note: `E::halt` should never be invoked as the base is a nonzero power of two.
+            Err(_) => E::halt("Failed to invert the limb base during modular multiplication"),
+        };
+        E::assert_eq(value, &carry * base);
+
+        // Ensure the carry is in range, by shifting it to be non-negative.
+        // Note: If `value` is not a multiple of `base`, the witnessed carry wraps around the base field modulus.
+        let offset = (1..num_bits).fold(Field::<E>::one(), |power, _| power.double());
+        (&carry + offset).to_lower_bits_le(num_bits);
+
+        carry
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn MulMod<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1, 2 * I::BITS < E::BaseField::size_in_data_bits() as u64) {
+            (Mode::Constant, Mode::Constant, _) => Count::is(I::BITS, 0, 0, 0),
+            (Mode::Constant, _, true) | (_, Mode::Constant, true) => Count::is(0, 0, 3 * I::BITS, 3 * I::BITS + 2),
+            (Mode::Constant, _, false) | (_, Mode::Constant, false) => {
+                Count::is(0, 0, 4 * I::BITS + 7, 4 * I::BITS + 11)
+            }
+            (_, _, true) => Count::is(0, 0, 3 * I::BITS + 1, 3 * I::BITS + 3),
+            (_, _, false) => Count::is(0, 0, 4 * I::BITS + 11, 4 * I::BITS + 15),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn MulMod<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+/// Returns `(a * b) % modulus`, using double-and-add so that no intermediate value overflows.
+pub(super) fn mul_mod<I: IntegerType>(a: I, b: I, modulus: I) -> I {
+    // Returns `(x + y) % modulus`, for `x` and `y` less than `modulus`.
+    let add_mod = |x: I, y: I| match x >= modulus - y {
+        true => x - (modulus - y),
+        false => x + y,
+    };
+    let a = a % modulus;
+    b.to_bits_le().iter().rev().fold(I::zero(), |result, bit| {
+        let result = add_mod(result, result);
+        match *bit {
+            true => add_mod(result, a),
+            false => result,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns `(a * b) % modulus`, computed from the least significant bit of `b` upwards.
+    fn reference_mul_mod(a: u128, b: u128, modulus: u128) -> u128 {
+        let add_mod = |x: u128, y: u128| match x.checked_add(y) {
+            Some(sum) => sum % modulus,
+            None => x.wrapping_add(y).wrapping_sub(modulus),
+        };
+        let (mut result, mut power, mut b) = (0, a % modulus, b);
+        while b != 0 {
+            if b & 1 == 1 {
+                result = add_mod(result, power);
+            }
+            power = add_mod(power, power);
+            b >>= 1;
+        }
+        result
+    }
+
+    fn check_mul_mod<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        modulus: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let m = Integer::<Circuit, I>::constant(modulus);
+        let expected =
+            reference_mul_mod(first.to_u128().unwrap(), second.to_u128().unwrap(), modulus.to_u128().unwrap());
+
+        Circuit::scope(name, || {
+            let candidate = a.mul_mod(&b, &m);
+            assert_eq!(expected, candidate.eject_value().to_u128().unwrap());
+            assert_count!(MulMod(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(MulMod(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let two = I::one() + I::one();
+        let mut moduli = vec![I::one(), two, I::MAX, I::MAX / two + I::one()];
+        moduli.extend((0..4).map(|_| match I::rand(&mut rng) {
+            modulus if modulus.is_zero() => I::one(),
+            modulus => modulus,
+        }));
+
+        for modulus in moduli {
+            for i in 0..ITERATIONS {
+                let first = I::rand(&mut rng) % modulus;
+                let second = I::rand(&mut rng) % modulus;
+
+                let name = format!("MulMod: ({mode_a} * {mode_b}) % {modulus} {i}");
+                check_mul_mod::<I>(
+                    &name,
+                    console::Integer::new(first),
+                    console::Integer::new(second),
+                    console::Integer::new(modulus),
+                    mode_a,
+                    mode_b,
+                );
+            }
+
+            // Check the largest operands, whose product overflows the integer type for large moduli.
+            let largest = console::Integer::new(modulus - I::one());
+            let name = format!("MulMod: ({mode_a} * {mode_b}) % {modulus} largest");
+            check_mul_mod::<I>(&name, largest, largest, console::Integer::new(modulus), mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_reference_mul_mod() {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let (a, b, modulus) = (u64::rand(&mut rng), u64::rand(&mut rng), u64::rand(&mut rng).max(1));
+            let expected = (a as u128 * b as u128) % modulus as u128;
+            assert_eq!(expected, reference_mul_mod(a as u128, b as u128, modulus as u128));
+        }
+    }
+
+    #[test]
+    fn test_mul_mod_halts() {
+        let one = console::Integer::<_, u8>::one();
+        let zero = console::Integer::<_, u8>::zero();
+
+        // Ensure a non-constant modulus halts.
+        let a = Integer::<Circuit, u8>::new(Mode::Private, zero);
+        let m = Integer::<Circuit, u8>::new(Mode::Private, one + one);
+        check_operation_halts(&a, &m, |a, m| a.mul_mod(a, m));
+
+        // Ensure a zero modulus halts.
+        let m = Integer::<Circuit, u8>::constant(zero);
+        check_operation_halts(&a, &m, |a, m| a.mul_mod(a, m));
+
+        // Ensure unreduced constant operands halt.
+        let a = Integer::<Circuit, u8>::constant(one + one);
+        let m = Integer::<Circuit, u8>::constant(one + one);
+        check_operation_halts(&a, &m, |a, m| a.mul_mod(a, m));
+
+        // Ensure a signed integer type halts.
+        let a = Integer::<Circuit, i8>::new(Mode::Private, console::Integer::zero());
+        let m = Integer::<Circuit, i8>::constant(console::Integer::one());
+        check_operation_halts(&a, &m, |a, m| a.mul_mod(a, m));
+
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, u8, mul_mod);
+    test_integer_binary!(run_test, u16, mul_mod);
+    test_integer_binary!(run_test, u32, mul_mod);
+    test_integer_binary!(run_test, u64, mul_mod);
+    test_integer_binary!(run_test, u128, mul_mod);
+}
@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> MulSaturating<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn mul_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the product and return the new constant.
+            witness!(|self, other| console::Integer::new(match (*self).checked_mul(&*other) {
+                Some(value) => value,
+                // Note: The product of two operands with different signs saturates to `I::MIN`.
+                None if (*self < I::zero()) != (*other < I::zero()) => I::MIN,
+                None => I::MAX,
+            }))
+        } else if I::is_signed() {
+            // Compute the wrapped product of `self` and `other`, along with an overflow flag.
+            let (product, is_overflow) = self.overflowing_mul(other);
+
+            // Determine the saturation bound, which is `I::MAX` if the operands share a sign, and `I::MIN` otherwise.
+            let operands_same_sign = self.msb().is_equal(other.msb());
+            let mut bound_bits_le = vec![operands_same_sign.clone(); I::BITS as usize - 1];
+            bound_bits_le.push(!operands_same_sign);
+            let bound = Integer { bits_le: bound_bits_le, phantom: Default::default() };
+
+            Self::ternary(&is_overflow, &bound, &product)
+        } else {
+            // Compute the wrapped product of `self` and `other`, along with an overflow flag.
+            let (product, is_overflow) = self.overflowing_mul(other);
+
+            // For unsigned multiplication, saturating to `I::MAX` is equivalent to setting every bit of the product.
+            Integer {
+                bits_le: product.bits_le.iter().map(|bit| &is_overflow | bit).collect(),
+                phantom: Default::default(),
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn MulSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Case 1 - 2 integers fit in 1 field element (u8, u16, u32, u64, i8, i16, i32, i64).
+        if 2 * I::BITS < (E::BaseField::size_in_bits() - 1) as u64 {
+            match I::is_signed() {
+                // Signed case
+                true => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => {
+                        Count::less_than((7 * I::BITS) + 1, 0, (8 * I::BITS) + 8, (8 * I::BITS) + 11)
+                    }
+                    (_, _) => Count::is(3 * I::BITS, 0, (10 * I::BITS) + 12, (10 * I::BITS) + 16),
+                },
+                // Unsigned case
+                false => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => {
+                        Count::less_than((2 * I::BITS) + 1, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 3)
+                    }
+                    (_, _) => Count::is(0, 0, (3 * I::BITS) + 3, (3 * I::BITS) + 4),
+                },
+            }
+        }
+        // Case 2 - 1.5 integers fit in 1 field element (u128, i128).
+        else if (I::BITS + I::BITS / 2) < (E::BaseField::size_in_bits() - 1) as u64 {
+            match I::is_signed() {
+                // Signed case
+                true => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(834, 0, 969, 974),
+                    (_, _) => Count::is(3 * I::BITS, 0, 1231, 1235),
+                },
+                // Unsigned case
+                false => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(194, 0, 323, 327),
+                    (_, _) => Count::is(0, 0, 326, 327),
+                },
+            }
+        } else {
+            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn MulSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_mul<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::new(mode_b, second);
+        let expected = match (*first).checked_mul(&*second) {
+            Some(value) => value,
+            None if (*first < I::zero()) != (*second < I::zero()) => I::MIN,
+            None => I::MAX,
+        };
+        Circuit::scope(name, || {
+            let candidate = a.mul_saturating(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(MulSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            // Note: a constant zero operand yields a constant product, as in `overflowing_mul`,
+            // so the output mode is only checked when neither operand is a constant zero.
+            let is_constant_zero = |mode: Mode, value: I| mode.is_constant() && value == I::zero();
+            if !is_constant_zero(mode_a, *first) && !is_constant_zero(mode_b, *second) {
+                assert_output_mode!(MulSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("MulSaturating: {mode_a} * {mode_b} {i}");
+            check_mul::<I>(&name, first, second, mode_a, mode_b);
+            check_mul::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+
+            let name = format!("Double: {mode_a} * {mode_b} {i}");
+            check_mul::<I>(&name, first, console::Integer::one() + console::Integer::one(), mode_a, mode_b);
+
+            let name = format!("Square: {mode_a} * {mode_b} {i}");
+            check_mul::<I>(&name, first, first, mode_a, mode_b);
+        }
+
+        // Check specific cases common to signed and unsigned integers.
+        check_mul::<I>("1 * MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+        check_mul::<I>("MIN * 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_mul::<I>("0 * MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        check_mul::<I>("MIN * 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+
+        // Overflow
+        let two = console::Integer::one() + console::Integer::one();
+        check_mul::<I>("MAX * 2", console::Integer::MAX, two, mode_a, mode_b);
+        check_mul::<I>("MAX * MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+
+        // Check additional corner cases for signed integers.
+        if I::is_signed() {
+            check_mul::<I>("MIN * MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+            check_mul::<I>("MIN * -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_mul::<I>("-1 * MIN", -console::Integer::one(), console::Integer::MIN, mode_a, mode_b);
+            check_mul::<I>("MAX * -1", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+            check_mul::<I>("MIN * 2", console::Integer::MIN, two, mode_a, mode_b);
+            check_mul::<I>("MAX * -2", console::Integer::MAX, -two, mode_a, mode_b);
+            check_mul::<I>("MIN * MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("MulSaturating: ({first} * {second})");
+                check_mul::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, times);
+    test_integer_binary!(run_test, i16, times);
+    test_integer_binary!(run_test, i32, times);
+    test_integer_binary!(run_test, i64, times);
+    test_integer_binary!(run_test, i128, times);
+
+    test_integer_binary!(run_test, u8, times);
+    test_integer_binary!(run_test, u16, times);
+    test_integer_binary!(run_test, u32, times);
+    test_integer_binary!(run_test, u64, times);
+    test_integer_binary!(run_test, u128, times);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, times, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, times, exhaustive);
+}
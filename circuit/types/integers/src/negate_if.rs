@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `-self` if `condition` is `true`, and `self` otherwise.
+    ///
+    /// As with `neg`, this halts if `condition` is `true` and `self` is `I::MIN`.
+    /// Note: `halt` is necessary since negation is not defined for unsigned integers.
+    pub fn negate_if(&self, condition: &Boolean<E>) -> Self {
+        if !I::is_signed() {
+            E::halt("Attempted to conditionally negate an unsigned integer")
+        }
+        // Constant `condition`
+        if condition.is_constant() {
+            match condition.eject_value() {
+                true => -self,
+                false => self.clone(),
+            }
+        }
+        // Variable `condition`
+        else {
+            let negation = Integer::zero().sub_wrapped(self);
+            // Ensure `self` is not `I::MIN` if it is negated.
+            // Note: `I::MIN` is the only negative value whose wrapped negation is also negative.
+            E::assert_eq(condition & &(self.msb() & negation.msb()), E::zero());
+            Self::ternary(condition, &negation, self)
+        }
+    }
+
+    /// Returns `-self` if `condition` is `true`, and `self` otherwise, wrapping `-I::MIN` to `I::MIN`.
+    pub fn negate_if_wrapped(&self, condition: &Boolean<E>) -> Self {
+        if !I::is_signed() {
+            E::halt("Attempted to conditionally negate an unsigned integer")
+        }
+        // Constant `condition`
+        if condition.is_constant() {
+            match condition.eject_value() {
+                true => Integer::zero().sub_wrapped(self),
+                false => self.clone(),
+            }
+        }
+        // Variable `condition`
+        else {
+            Self::ternary(condition, &Integer::zero().sub_wrapped(self), self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_negate_if<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        flag: bool,
+        mode: Mode,
+        mode_condition: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let condition = Boolean::<Circuit>::new(mode_condition, flag);
+
+        // Check the wrapped variant.
+        let expected = match flag {
+            true => value.wrapping_neg(),
+            false => *value,
+        };
+        Circuit::scope(format!("{name} wrapped"), || {
+            let candidate = a.negate_if_wrapped(&condition);
+            assert_eq!(expected, *candidate.eject_value());
+            match (mode, mode_condition, flag) {
+                (_, Mode::Constant, false) => assert_scope!(0, 0, 0, 0),
+                (Mode::Constant, _, _) => assert_scope!(2 * I::BITS, 0, 0, 0),
+                (_, Mode::Constant, true) => assert_scope!(I::BITS, 0, I::BITS + 1, I::BITS + 2),
+                (_, _, _) => assert_scope!(I::BITS, 0, 2 * I::BITS + 1, 2 * I::BITS + 2),
+            }
+        });
+        Circuit::reset();
+
+        // Check the checked variant.
+        let is_overflow = flag && *value == I::MIN;
+        match (is_overflow, mode.is_constant() && mode_condition.is_constant()) {
+            (true, true) => check_unary_operation_halts(&a, |a| a.negate_if(&condition)),
+            (true, false) => Circuit::scope(name, || {
+                let _candidate = a.negate_if(&condition);
+                assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+            }),
+            (false, _) => Circuit::scope(name, || {
+                let candidate = a.negate_if(&condition);
+                assert_eq!(expected, *candidate.eject_value());
+                match (mode, mode_condition, flag) {
+                    (_, Mode::Constant, false) => assert_scope!(0, 0, 0, 0),
+                    (Mode::Constant, Mode::Constant, true) => assert_scope!(2 * I::BITS, 0, 0, 0),
+                    (_, Mode::Constant, true) => assert_scope!(I::BITS, 0, I::BITS + 2, I::BITS + 4),
+                    // Note: The number of constraints for a constant `self` depends on its sign.
+                    (Mode::Constant, _, _) => assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)"),
+                    (_, _, _) => assert_scope!(I::BITS, 0, 2 * I::BITS + 3, 2 * I::BITS + 5),
+                }
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode: Mode, mode_condition: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            for flag in [true, false] {
+                let name = format!("NegateIf({flag}): {mode} {mode_condition} {i}");
+                check_negate_if::<I>(&name, value, flag, mode, mode_condition);
+            }
+        }
+
+        for flag in [true, false] {
+            let name = format!("NegateIf({flag}): {mode} {mode_condition}");
+            check_negate_if::<I>(&format!("{name} zero"), console::Integer::zero(), flag, mode, mode_condition);
+            check_negate_if::<I>(&format!("{name} one"), console::Integer::one(), flag, mode, mode_condition);
+            check_negate_if::<I>(&format!("{name} MAX"), console::Integer::MAX, flag, mode, mode_condition);
+            check_negate_if::<I>(&format!("{name} MIN"), console::Integer::MIN, flag, mode, mode_condition);
+        }
+    }
+
+    fn assert_unsigned_negate_if_halts<I: IntegerType + RefUnwindSafe>(mode: Mode, mode_condition: Mode) {
+        let a = Integer::<Circuit, I>::new(mode, Uniform::rand(&mut TestRng::default()));
+        let condition = Boolean::<Circuit>::new(mode_condition, true);
+        check_unary_operation_halts(&a, |a| a.negate_if(&condition));
+        check_unary_operation_halts(&a, |a| a.negate_if_wrapped(&condition));
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, i8, negate_if);
+    test_integer_binary!(run_test, i16, negate_if);
+    test_integer_binary!(run_test, i32, negate_if);
+    test_integer_binary!(run_test, i64, negate_if);
+    test_integer_binary!(run_test, i128, negate_if);
+
+    test_integer_binary!(assert_unsigned_negate_if_halts, u8, negate_if);
+    test_integer_binary!(assert_unsigned_negate_if_halts, u16, negate_if);
+    test_integer_binary!(assert_unsigned_negate_if_halts, u32, negate_if);
+    test_integer_binary!(assert_unsigned_negate_if_halts, u64, negate_if);
+    test_integer_binary!(assert_unsigned_negate_if_halts, u128, negate_if);
+}
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Nor<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `NOT (self OR other)`.
+    fn nor(&self, other: &Integer<E, I>) -> Self::Output {
+        // Note: Each bit is computed with a single NOR gate, so the negation does not cost an additional constraint.
+        Self {
+            bits_le: self.bits_le.iter().zip_eq(other.bits_le.iter()).map(|(a, b)| a.nor(b)).collect(),
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Nor<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (_, _) => Count::is(0, 0, I::BITS, I::BITS),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Nor<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0.mode(), case.1.mode()) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (Mode::Constant, mode_b) => match &case.0 {
+                // Determine if the constant is all ones.
+                CircuitType::Constant(constant) => match (!constant.eject_value()).is_zero() {
+                    true => Mode::Constant,
+                    false => Mode::Private,
+                },
+                _ => E::halt(format!("The constant is required to determine the output mode of Constant NOR {mode_b}")),
+            },
+            (mode_a, Mode::Constant) => match &case.1 {
+                // Determine if the constant is all ones.
+                CircuitType::Constant(constant) => match (!constant.eject_value()).is_zero() {
+                    true => Mode::Constant,
+                    false => Mode::Private,
+                },
+                _ => E::halt(format!("The constant is required to determine the output mode of {mode_a} NOR Constant")),
+            },
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use std::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_nor<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::new(mode_b, second);
+        let expected = !(first | second);
+        Circuit::scope(name, || {
+            let candidate = a.nor(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Nor(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Nor(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Nor: ({mode_a} nor {mode_b}) {i}");
+            check_nor::<I>(&name, first, second, mode_a, mode_b);
+            check_nor::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+        }
+
+        // Check the all-zeros and all-ones operands.
+        let all_ones = !console::Integer::zero();
+        check_nor::<I>("0, 0", console::Integer::zero(), console::Integer::zero(), mode_a, mode_b);
+        check_nor::<I>("0, 1s", console::Integer::zero(), all_ones, mode_a, mode_b);
+        check_nor::<I>("1s, 0", all_ones, console::Integer::zero(), mode_a, mode_b);
+        check_nor::<I>("1s, 1s", all_ones, all_ones, mode_a, mode_b);
+        check_nor::<I>("MAX, MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("Nor: ({first} nor {second})");
+                check_nor::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, nor);
+    test_integer_binary!(run_test, i16, nor);
+    test_integer_binary!(run_test, i32, nor);
+    test_integer_binary!(run_test, i64, nor);
+    test_integer_binary!(run_test, i128, nor);
+
+    test_integer_binary!(run_test, u8, nor);
+    test_integer_binary!(run_test, u16, nor);
+    test_integer_binary!(run_test, u32, nor);
+    test_integer_binary!(run_test, u64, nor);
+    test_integer_binary!(run_test, u128, nor);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, nor, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, nor, exhaustive);
+}
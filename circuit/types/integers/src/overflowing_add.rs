@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> OverflowingAdd<Self> for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns the wrapped sum of `self` and `other`, along with a flag indicating whether an overflow occurred.
+    #[inline]
+    fn overflowing_add(&self, other: &Integer<E, I>) -> (Self::Output, Self::Boolean) {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the wrapped sum and the overflow flag, and return the new constants.
+            let is_overflow = self.eject_value().checked_add(&other.eject_value()).is_none();
+            (witness!(|self, other| console::Integer::new(self.wrapping_add(&other))), Boolean::constant(is_overflow))
+        } else {
+            // Instead of adding the bits of `self` and `other` directly, the integers are
+            // converted into a field elements, and summed, before converting back to integers.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            let sum = self.to_field() + other.to_field();
+
+            // Extract the integer bits and the carry bit from the field element.
+            let (carry, sum) = match sum.to_lower_bits_le(I::BITS as usize + 1).split_last() {
+                Some((carry, bits_le)) => (carry.clone(), Integer::from_bits_le(bits_le)),
+                // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                None => E::halt("Malformed sum detected during integer addition"),
+            };
+
+            let is_overflow = match I::is_signed() {
+                // For signed addition, overflow and underflow conditions are:
+                //   - a > 0 && b > 0 && a + b < 0 (Overflow)
+                //   - a < 0 && b < 0 && a + b > 0 (Underflow)
+                //   - Note: if sign(a) != sign(b) then over/underflow is impossible.
+                //   - Note: the result of an overflow and underflow must be negative and positive, respectively.
+                true => self.msb().is_equal(other.msb()) & sum.msb().is_not_equal(self.msb()),
+                // For unsigned addition, an overflow occurs if and only if the carry bit is set.
+                false => carry,
+            };
+
+            (sum, is_overflow)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType>
+    Metrics<dyn OverflowingAdd<Integer<E, I>, Boolean = Boolean<E>, Output = Integer<E, I>>> for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match I::is_signed() {
+            true => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (Mode::Constant, _) => Count::is(0, 0, I::BITS + 2, I::BITS + 3),
+                (_, Mode::Constant) => Count::is(0, 0, I::BITS + 3, I::BITS + 4),
+                (_, _) => Count::is(0, 0, I::BITS + 4, I::BITS + 5),
+            },
+            false => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 2),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType>
+    OutputMode<dyn OverflowingAdd<Integer<E, I>, Boolean = Boolean<E>, Output = Integer<E, I>>> for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_overflowing_add<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let expected_sum = console::Integer::new(first.wrapping_add(&second));
+        let expected_overflow = first.checked_add(&second).is_none();
+        Circuit::scope(name, || {
+            let (candidate_sum, candidate_overflow) = a.overflowing_add(&b);
+            assert_eq!(expected_sum, candidate_sum.eject_value());
+            assert_eq!(expected_overflow, candidate_overflow.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                OverflowingAdd<Integer<Circuit, I>, Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_a, mode_b)
+            );
+            assert_output_mode!(
+                Integer<Circuit, I>,
+                OverflowingAdd<Integer<Circuit, I>, Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_a, mode_b),
+                candidate_sum
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("OverflowingAdd: {mode_a} + {mode_b} {i}");
+            check_overflowing_add::<I>(&name, first, second, mode_a, mode_b);
+            check_overflowing_add::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+        }
+
+        // No overflow
+        check_overflowing_add::<I>("MAX + 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_overflowing_add::<I>("MIN + 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+
+        // Overflow
+        check_overflowing_add::<I>("MAX + 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_overflowing_add::<I>("MAX + MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+
+        if I::is_signed() {
+            // No overflow
+            check_overflowing_add::<I>("MAX + MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+            check_overflowing_add::<I>("MIN + 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+
+            // Underflow
+            check_overflowing_add::<I>("MIN + (-1)", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_overflowing_add::<I>("MIN + MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("OverflowingAdd: ({first} + {second})");
+                check_overflowing_add::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, overflowing_add);
+    test_integer_binary!(run_test, i16, overflowing_add);
+    test_integer_binary!(run_test, i32, overflowing_add);
+    test_integer_binary!(run_test, i64, overflowing_add);
+    test_integer_binary!(run_test, i128, overflowing_add);
+
+    test_integer_binary!(run_test, u8, overflowing_add);
+    test_integer_binary!(run_test, u16, overflowing_add);
+    test_integer_binary!(run_test, u32, overflowing_add);
+    test_integer_binary!(run_test, u64, overflowing_add);
+    test_integer_binary!(run_test, u128, overflowing_add);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, overflowing_add, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, overflowing_add, exhaustive);
+}
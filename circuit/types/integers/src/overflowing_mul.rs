@@ -0,0 +1,274 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> OverflowingMul<Self> for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns the wrapped product of `self` and `other`, along with a flag indicating whether an overflow occurred.
+    #[inline]
+    fn overflowing_mul(&self, other: &Integer<E, I>) -> (Self::Output, Self::Boolean) {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the wrapped product and the overflow flag, and return the new constants.
+            let is_overflow = self.eject_value().checked_mul(&other.eject_value()).is_none();
+            (witness!(|self, other| console::Integer::new(self.wrapping_mul(&other))), Boolean::constant(is_overflow))
+        } else if I::is_signed() {
+            // Compute the wrapped product of `abs(self)` and `abs(other)`, along with a flag for the unsigned overflow.
+            // Note: it is safe to use `abs_wrapped` as we want `Integer::MIN` to be interpreted as an unsigned number.
+            let (product, product_overflows) = Self::mul_and_flag(&self.abs_wrapped(), &other.abs_wrapped());
+
+            // If the product should be positive, then it cannot exceed the signed maximum.
+            let operands_same_sign = &self.msb().is_equal(other.msb());
+            let positive_product_overflows = operands_same_sign & product.msb();
+
+            // If the product should be negative, then it cannot exceed the absolute value of the signed minimum.
+            let negative_product_underflows = {
+                let lower_product_bits_nonzero =
+                    product.bits_le[..(I::BITS as usize - 1)].iter().fold(Boolean::constant(false), |a, b| a | b);
+                let negative_product_lt_or_eq_signed_min =
+                    !product.msb() | (product.msb() & !lower_product_bits_nonzero);
+                !operands_same_sign & !negative_product_lt_or_eq_signed_min
+            };
+            let is_overflow = product_overflows | positive_product_overflows | negative_product_underflows;
+
+            // Return the wrapped product of `self` and `other` with the appropriate sign.
+            // Note: this is correct on overflow, as the lower bits of `abs(self) * abs(other)` are retained.
+            (Self::ternary(operands_same_sign, &product, &Self::zero().sub_wrapped(&product)), is_overflow)
+        } else {
+            // Compute the wrapped product of `self` and `other`, along with the overflow flag.
+            Self::mul_and_flag(self, other)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Multiply the integer bits of `this` and `that`, returning the wrapped product and an overflow flag.
+    /// This function assumes that `this` and `that` are non-negative.
+    #[inline]
+    fn mul_and_flag(this: &Integer<E, I>, that: &Integer<E, I>) -> (Integer<E, I>, Boolean<E>) {
+        // Case 1 - 2 integers fit in 1 field element (u8, u16, u32, u64, i8, i16, i32, i64).
+        if 2 * I::BITS < (E::BaseField::size_in_bits() - 1) as u64 {
+            // Compute the full product of `this` and `that`, in the base field.
+            // Note: The multiplication is safe as the field twice as large as the maximum integer type supported.
+            let product = this.to_field() * that.to_field();
+
+            // Split the bits of the full product into the wrapped product bits and the carry bits.
+            let bits_le = product.to_lower_bits_le(2 * I::BITS as usize);
+            let (bits_le, carry) = bits_le.split_at(I::BITS as usize);
+
+            // The product overflows if and only if any of the carry bits are set.
+            let is_overflow = Field::from_bits_le(carry).is_not_equal(&Field::zero());
+
+            (Integer::from_bits_le(bits_le), is_overflow)
+        }
+        // Case 2 - 1.5 integers fit in 1 field element (u128, i128).
+        else if (I::BITS + I::BITS / 2) < (E::BaseField::size_in_bits() - 1) as u64 {
+            // Use Karatsuba multiplication to compute the product of `self` and `other`.
+            let (product, z_1_upper_bits, z2) = Self::karatsuba_multiply(this, that);
+
+            // The product overflows if and only if any of the upper bits of z1 are set, or `z2` is nonzero.
+            // Note: The sum does not wrap around, as both terms are non-negative and less than `2^(I::BITS + 1)`.
+            let is_overflow = (Field::from_bits_le(&z_1_upper_bits) + z2).is_not_equal(&Field::zero());
+
+            (product, is_overflow)
+        } else {
+            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType>
+    Metrics<dyn OverflowingMul<Integer<E, I>, Boolean = Boolean<E>, Output = Integer<E, I>>> for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Case 1 - 2 integers fit in 1 field element (u8, u16, u32, u64, i8, i16, i32, i64).
+        if 2 * I::BITS < (E::BaseField::size_in_bits() - 1) as u64 {
+            match I::is_signed() {
+                // Signed case
+                true => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => {
+                        Count::less_than((7 * I::BITS) + 1, 0, (7 * I::BITS) + 8, (7 * I::BITS) + 11)
+                    }
+                    (_, _) => Count::is(3 * I::BITS, 0, (9 * I::BITS) + 11, (9 * I::BITS) + 15),
+                },
+                // Unsigned case
+                false => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => {
+                        Count::less_than((2 * I::BITS) + 1, 0, (2 * I::BITS) + 2, (2 * I::BITS) + 3)
+                    }
+                    (_, _) => Count::is(0, 0, (2 * I::BITS) + 3, (2 * I::BITS) + 4),
+                },
+            }
+        }
+        // Case 2 - 1.5 integers fit in 1 field element (u128, i128).
+        else if (I::BITS + I::BITS / 2) < (E::BaseField::size_in_bits() - 1) as u64 {
+            match I::is_signed() {
+                // Signed case
+                true => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(834, 0, 841, 846),
+                    (_, _) => Count::is(3 * I::BITS, 0, 1102, 1106),
+                },
+                // Unsigned case
+                false => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(194, 0, 195, 199),
+                    (_, _) => Count::is(0, 0, 198, 199),
+                },
+            }
+        } else {
+            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType>
+    OutputMode<dyn OverflowingMul<Integer<E, I>, Boolean = Boolean<E>, Output = Integer<E, I>>> for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_overflowing_mul<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let expected_product = console::Integer::new(first.wrapping_mul(&second));
+        let expected_overflow = first.checked_mul(&second).is_none();
+        Circuit::scope(name, || {
+            let (candidate_product, candidate_overflow) = a.overflowing_mul(&b);
+            assert_eq!(expected_product, candidate_product.eject_value());
+            assert_eq!(expected_overflow, candidate_overflow.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                OverflowingMul<Integer<Circuit, I>, Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_a, mode_b)
+            );
+            // Note: a constant zero operand yields a constant product, so the output mode is only checked
+            // when the operands are either both constant or both non-constant.
+            if mode_a.is_constant() == mode_b.is_constant() {
+                assert_output_mode!(
+                    Integer<Circuit, I>,
+                    OverflowingMul<Integer<Circuit, I>, Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                    &(mode_a, mode_b),
+                    candidate_product
+                );
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let two = console::Integer::one() + console::Integer::one();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("OverflowingMul: {mode_a} * {mode_b} {i}");
+            check_overflowing_mul::<I>(&name, first, second, mode_a, mode_b);
+            check_overflowing_mul::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+
+            let name = format!("Double: {mode_a} * {mode_b} {i}");
+            check_overflowing_mul::<I>(&name, first, two, mode_a, mode_b);
+
+            let name = format!("Square: {mode_a} * {mode_b} {i}");
+            check_overflowing_mul::<I>(&name, first, first, mode_a, mode_b);
+        }
+
+        // No overflow
+        check_overflowing_mul::<I>("1 * MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+        check_overflowing_mul::<I>("MIN * 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_overflowing_mul::<I>("0 * MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        check_overflowing_mul::<I>("MIN * 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+
+        // Overflow
+        check_overflowing_mul::<I>("MAX * 2", console::Integer::MAX, two, mode_a, mode_b);
+        check_overflowing_mul::<I>("MAX * MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+
+        if I::is_signed() {
+            // No overflow
+            check_overflowing_mul::<I>("MAX * -1", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+            check_overflowing_mul::<I>("-1 * -1", -console::Integer::one(), -console::Integer::one(), mode_a, mode_b);
+
+            // Overflow
+            check_overflowing_mul::<I>("MIN * -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_overflowing_mul::<I>("MIN * MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+
+            // Underflow
+            check_overflowing_mul::<I>("MIN * 2", console::Integer::MIN, two, mode_a, mode_b);
+            check_overflowing_mul::<I>("MAX * MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+            check_overflowing_mul::<I>("MAX * -2", console::Integer::MAX, -two, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("OverflowingMul: ({first} * {second})");
+                check_overflowing_mul::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, overflowing_mul);
+    test_integer_binary!(run_test, i16, overflowing_mul);
+    test_integer_binary!(run_test, i32, overflowing_mul);
+    test_integer_binary!(run_test, i64, overflowing_mul);
+    test_integer_binary!(run_test, i128, overflowing_mul);
+
+    test_integer_binary!(run_test, u8, overflowing_mul);
+    test_integer_binary!(run_test, u16, overflowing_mul);
+    test_integer_binary!(run_test, u32, overflowing_mul);
+    test_integer_binary!(run_test, u64, overflowing_mul);
+    test_integer_binary!(run_test, u128, overflowing_mul);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, overflowing_mul, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, overflowing_mul, exhaustive);
+}
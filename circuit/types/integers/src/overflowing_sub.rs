@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> OverflowingSub<Self> for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns the wrapped difference of `self` and `other`, along with a flag indicating whether an overflow occurred.
+    #[inline]
+    fn overflowing_sub(&self, other: &Integer<E, I>) -> (Self::Output, Self::Boolean) {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the wrapped difference and the overflow flag, and return the new constants.
+            let is_overflow = self.eject_value().checked_sub(&other.eject_value()).is_none();
+            (witness!(|self, other| console::Integer::new(self.wrapping_sub(&other))), Boolean::constant(is_overflow))
+        } else {
+            // Instead of subtracting the bits of `self` and `other` directly, the integers are
+            // converted into a field elements, and subtracted, before converting back to integers.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            let difference = self.to_field() + (!other).to_field() + Field::one();
+
+            // Extract the integer bits from the field element, with a carry bit.
+            let (difference, carry) = match difference.to_lower_bits_le(I::BITS as usize + 1).split_last() {
+                Some((carry, bits_le)) => (Integer::from_bits_le(bits_le), carry.clone()),
+                // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                None => E::halt("Malformed difference detected during integer subtraction"),
+            };
+
+            let is_overflow = match I::is_signed() {
+                // For signed subtraction, overflow and underflow conditions are:
+                //   - a > 0 && b < 0 && a - b < 0 (Overflow)
+                //   - a < 0 && b > 0 && a - b > 0 (Underflow)
+                //   - Note: if sign(a) == sign(b) then over/underflow is impossible.
+                //   - Note: the result of an overflow and underflow must be negative and positive, respectively.
+                true => {
+                    let is_different_signs = self.msb().is_not_equal(other.msb());
+                    is_different_signs & difference.msb().is_equal(other.msb())
+                }
+                // For unsigned subtraction, an underflow occurs if and only if the carry bit is not set.
+                false => !carry,
+            };
+
+            (difference, is_overflow)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType>
+    Metrics<dyn OverflowingSub<Integer<E, I>, Boolean = Boolean<E>, Output = Integer<E, I>>> for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match I::is_signed() {
+            true => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (Mode::Constant, _) => Count::is(0, 0, I::BITS + 3, I::BITS + 4),
+                (_, Mode::Constant) => Count::is(0, 0, I::BITS + 2, I::BITS + 3),
+                (_, _) => Count::is(0, 0, I::BITS + 4, I::BITS + 5),
+            },
+            false => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 2),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType>
+    OutputMode<dyn OverflowingSub<Integer<E, I>, Boolean = Boolean<E>, Output = Integer<E, I>>> for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_overflowing_sub<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let expected_difference = console::Integer::new(first.wrapping_sub(&second));
+        let expected_overflow = first.checked_sub(&second).is_none();
+        Circuit::scope(name, || {
+            let (candidate_difference, candidate_overflow) = a.overflowing_sub(&b);
+            assert_eq!(expected_difference, candidate_difference.eject_value());
+            assert_eq!(expected_overflow, candidate_overflow.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                OverflowingSub<Integer<Circuit, I>, Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_a, mode_b)
+            );
+            assert_output_mode!(
+                Integer<Circuit, I>,
+                OverflowingSub<Integer<Circuit, I>, Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_a, mode_b),
+                candidate_difference
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("OverflowingSub: {mode_a} - {mode_b} {i}");
+            check_overflowing_sub::<I>(&name, first, second, mode_a, mode_b);
+            check_overflowing_sub::<I>(&name, second, first, mode_a, mode_b); // Swap the operands.
+        }
+
+        // No overflow
+        check_overflowing_sub::<I>("MAX - 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_overflowing_sub::<I>("MIN - 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+        check_overflowing_sub::<I>("MAX - MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+
+        // Underflow
+        check_overflowing_sub::<I>("MIN - 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+
+        if I::is_signed() {
+            // No overflow
+            check_overflowing_sub::<I>("MIN - MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+            check_overflowing_sub::<I>("-1 - MAX", -console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+            check_overflowing_sub::<I>("-1 - MIN", -console::Integer::one(), console::Integer::MIN, mode_a, mode_b);
+
+            // Overflow
+            check_overflowing_sub::<I>("MAX - (-1)", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+            check_overflowing_sub::<I>("0 - MIN", console::Integer::zero(), console::Integer::MIN, mode_a, mode_b);
+            check_overflowing_sub::<I>("MAX - MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+
+            // Underflow
+            check_overflowing_sub::<I>("MIN - MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        } else {
+            // Underflow
+            check_overflowing_sub::<I>("0 - MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+            check_overflowing_sub::<I>(
+                "1 - 2",
+                console::Integer::one(),
+                console::Integer::one() + console::Integer::one(),
+                mode_a,
+                mode_b,
+            );
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("OverflowingSub: ({first} - {second})");
+                check_overflowing_sub::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, overflowing_sub);
+    test_integer_binary!(run_test, i16, overflowing_sub);
+    test_integer_binary!(run_test, i32, overflowing_sub);
+    test_integer_binary!(run_test, i64, overflowing_sub);
+    test_integer_binary!(run_test, i128, overflowing_sub);
+
+    test_integer_binary!(run_test, u8, overflowing_sub);
+    test_integer_binary!(run_test, u16, overflowing_sub);
+    test_integer_binary!(run_test, u32, overflowing_sub);
+    test_integer_binary!(run_test, u64, overflowing_sub);
+    test_integer_binary!(run_test, u128, overflowing_sub);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, overflowing_sub, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, overflowing_sub, exhaustive);
+}
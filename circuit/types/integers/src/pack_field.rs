@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Packs the given integers into a single base field element, where the bits of the first integer
+    /// form the least significant bits of the field element.
+    ///
+    /// This is a linear combination of the bits, and requires no constraints.
+    /// Halts if the total number of bits exceeds the data capacity of the base field.
+    pub fn pack_into_field(integers: &[Self]) -> Field<E> {
+        // Ensure the total number of bits fits within the data capacity of the base field.
+        let num_bits = integers.len() * I::BITS as usize;
+        if num_bits > E::BaseField::size_in_data_bits() {
+            E::halt(format!(
+                "Attempted to pack {num_bits} bits into a base field element with capacity {}",
+                E::BaseField::size_in_data_bits()
+            ))
+        }
+        let bits_le = integers.iter().flat_map(|integer| integer.bits_le.iter().cloned()).collect::<Vec<_>>();
+        Field::from_bits_le(&bits_le)
+    }
+
+    /// Unpacks `num_integers` integers from the given base field element, the inverse of `pack_into_field`.
+    ///
+    /// This enforces that the bits above the packed integers are zero, and costs `num_integers * I::BITS + 1`
+    /// constraints for a non-constant field element.
+    /// Halts if the total number of bits exceeds the data capacity of the base field.
+    pub fn unpack_from_field(field: &Field<E>, num_integers: usize) -> Vec<Self> {
+        // Ensure the total number of bits fits within the data capacity of the base field.
+        let num_bits = num_integers * I::BITS as usize;
+        if num_bits > E::BaseField::size_in_data_bits() {
+            E::halt(format!(
+                "Attempted to unpack {num_bits} bits from a base field element with capacity {}",
+                E::BaseField::size_in_data_bits()
+            ))
+        }
+        field
+            .to_lower_bits_le(num_bits)
+            .chunks(I::BITS as usize)
+            .map(|bits_le| Integer { bits_le: bits_le.to_vec(), phantom: Default::default() })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 8;
+
+    fn check_pack_into_field<I: IntegerType + RefUnwindSafe>(name: &str, num_integers: usize, mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let values = (0..num_integers).map(|_| Uniform::rand(&mut rng)).collect::<Vec<console::Integer<_, I>>>();
+        let integers = values.iter().map(|value| Integer::<Circuit, I>::new(mode, *value)).collect::<Vec<_>>();
+
+        // Compute the expected field element from the concatenated bits.
+        let expected_bits_le = values.iter().flat_map(|value| value.to_bits_le()).collect::<Vec<_>>();
+        let expected = console::Field::from_bits_le(&expected_bits_le).unwrap();
+
+        Circuit::scope(name, || {
+            let candidate = Integer::pack_into_field(&integers);
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+
+            // Ensure the field element unpacks to the original integers.
+            let candidates = Integer::<Circuit, I>::unpack_from_field(&candidate, num_integers);
+            assert_eq!(values, candidates.iter().map(|candidate| candidate.eject_value()).collect::<Vec<_>>());
+            let num_bits = num_integers as u64 * I::BITS;
+            match mode.is_constant() {
+                true => assert_scope!(num_bits, 0, 0, 0),
+                false => assert_scope!(0, 0, num_bits, num_bits + 1),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let capacity = <Circuit as Environment>::BaseField::size_in_data_bits() / I::BITS as usize;
+
+        for i in 0..ITERATIONS {
+            for num_integers in [1, 2, capacity].into_iter().filter(|n| *n <= capacity) {
+                let name = format!("Pack: {mode} {num_integers} {i}");
+                check_pack_into_field::<I>(&name, num_integers, mode);
+            }
+        }
+
+        // Ensure packing or unpacking beyond the capacity halts.
+        let integers = vec![Integer::<Circuit, I>::new(mode, console::Integer::MAX); capacity + 1];
+        check_unary_operation_halts(&integers, |integers| Integer::pack_into_field(integers));
+        let field = Field::<Circuit>::new(mode, console::Field::one());
+        check_unary_operation_halts(&field, |field| Integer::<Circuit, I>::unpack_from_field(field, capacity + 1));
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_unpack_out_of_range_fails() {
+        // Ensure a field element with bits above the packed integers is rejected.
+        let value = console::Field::<<Circuit as Environment>::Network>::from_u64(1 << 16);
+        let field = Field::<Circuit>::new(Mode::Private, value);
+        let _candidates = U8::<Circuit>::unpack_from_field(&field, 2);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, pack_into_field);
+    test_integer_unary!(run_test, i16, pack_into_field);
+    test_integer_unary!(run_test, i32, pack_into_field);
+    test_integer_unary!(run_test, i64, pack_into_field);
+    test_integer_unary!(run_test, i128, pack_into_field);
+
+    test_integer_unary!(run_test, u8, pack_into_field);
+    test_integer_unary!(run_test, u16, pack_into_field);
+    test_integer_unary!(run_test, u32, pack_into_field);
+    test_integer_unary!(run_test, u64, pack_into_field);
+    test_integer_unary!(run_test, u128, pack_into_field);
+}
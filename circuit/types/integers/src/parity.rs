@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Parity for Integer<E, I> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if the binary representation of `self` has an odd number of ones.
+    fn parity(&self) -> Self::Output {
+        // The parity is the XOR of all of the bits.
+        // Note: The XOR of the first bit with the constant `false` is free.
+        self.bits_le.iter().fold(Boolean::constant(false), |parity, bit| parity ^ bit)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Parity<Output = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(0, 0, 0, 0),
+            _ => Count::is(0, 0, I::BITS - 1, I::BITS - 1),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Parity<Output = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_parity<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = (*value).count_ones() % 2 == 1;
+        Circuit::scope(name, || {
+            let candidate = a.parity();
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Integer<Circuit, I>, Parity<Output = Boolean<Circuit>>, &mode);
+            assert_output_mode!(Integer<Circuit, I>, Parity<Output = Boolean<Circuit>>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("Parity: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_parity::<I>(&name, value, mode);
+        }
+
+        // Check the zero, one, and bounds cases.
+        for value in [console::Integer::zero(), console::Integer::one(), console::Integer::MAX, console::Integer::MIN] {
+            let name = format!("Parity: {mode} {value}");
+            check_parity::<I>(&name, value, mode);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("Parity: {mode} {value}");
+            check_parity::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, parity);
+    test_integer_unary!(run_test, i16, parity);
+    test_integer_unary!(run_test, i32, parity);
+    test_integer_unary!(run_test, i64, parity);
+    test_integer_unary!(run_test, i128, parity);
+
+    test_integer_unary!(run_test, u8, parity);
+    test_integer_unary!(run_test, u16, parity);
+    test_integer_unary!(run_test, u32, parity);
+    test_integer_unary!(run_test, u64, parity);
+    test_integer_unary!(run_test, u128, parity);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, parity, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, parity, exhaustive);
+}
@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> PowMod<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self ^ other % modulus`, for a constant `modulus`.
+    /// This operation is only defined for unsigned integers.
+    ///
+    /// This uses square-and-multiply over the bits of `other`, where each bit performs two `mul_mod`
+    /// operations and selects the result with a `ternary`. The number of constraints is thus roughly
+    /// `2 * I::BITS` times the number of constraints of `mul_mod`, i.e. quadratic in `I::BITS`.
+    ///
+    /// Note: As in `mul_mod`, this assumes that `self` is less than `modulus`.
+    #[inline]
+    fn pow_mod(&self, other: &Integer<E, I>, modulus: &Integer<E, I>) -> Self::Output {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("Modular exponentiation is only defined for unsigned integers")
+        }
+        // Ensure the modulus is a nonzero constant.
+        if !modulus.is_constant() {
+            E::halt("The modulus of a modular exponentiation must be a constant")
+        }
+        if *modulus.eject_value() == I::zero() {
+            E::halt("The modulus of a modular exponentiation must be nonzero")
+        }
+
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Ensure the base is reduced, as in the non-constant case.
+            if self.eject_value() >= modulus.eject_value() {
+                E::halt("The base of a modular exponentiation must be less than the modulus")
+            }
+            // Compute the result and return the new constant.
+            witness!(|self, other, modulus| console::Integer::new(pow_mod(*self, *other, *modulus)))
+        } else {
+            // Note: The initial result is reduced, so that `1 % 1 == 0`.
+            let mut result = Self::constant(console::Integer::new(I::one() % *modulus.eject_value()));
+            for bit in other.bits_le.iter().rev() {
+                result = result.mul_mod(&result, modulus);
+                result = Self::ternary(bit, &result.mul_mod(self, modulus), &result);
+            }
+            result
+        }
+    }
+}
+
+/// Returns `base ^ exponent % modulus`, using square-and-multiply.
+pub(super) fn pow_mod<I: IntegerType>(base: I, exponent: I, modulus: I) -> I {
+    exponent.to_bits_le().iter().rev().fold(I::one() % modulus, |result, bit| {
+        let result = mul_mod::mul_mod(result, result, modulus);
+        match *bit {
+            true => mul_mod::mul_mod(result, base, modulus),
+            false => result,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 8;
+
+    /// Returns `base ^ exponent % modulus`, by repeated multiplication over `u128`.
+    /// Note: This requires `modulus` to be less than `2^64`, so that the products do not overflow.
+    fn reference_pow_mod(base: u128, exponent: u128, modulus: u128) -> u128 {
+        let (mut result, mut power, mut exponent) = (1 % modulus, base % modulus, exponent);
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result = result * power % modulus;
+            }
+            power = power * power % modulus;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    fn check_pow_mod<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        modulus: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let m = Integer::<Circuit, I>::constant(modulus);
+        let expected =
+            reference_pow_mod(first.to_u128().unwrap(), second.to_u128().unwrap(), modulus.to_u128().unwrap());
+
+        Circuit::scope(name, || {
+            let candidate = a.pow_mod(&b, &m);
+            assert_eq!(expected, candidate.eject_value().to_u128().unwrap());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let two = I::one() + I::one();
+        let three = two + I::one();
+        let small_moduli = [I::one(), two, three + two, three * three * three + two + two];
+
+        for modulus in small_moduli {
+            for i in 0..ITERATIONS {
+                let base = I::rand(&mut rng) % modulus;
+                let exponent = I::rand(&mut rng);
+
+                let name = format!("PowMod: ({mode_a} ^ {mode_b}) % {modulus} {i}");
+                check_pow_mod::<I>(
+                    &name,
+                    console::Integer::new(base),
+                    console::Integer::new(exponent),
+                    console::Integer::new(modulus),
+                    mode_a,
+                    mode_b,
+                );
+            }
+
+            // Check the zero and maximum exponents.
+            for exponent in [I::zero(), I::one(), I::MAX] {
+                let name = format!("PowMod: ({mode_a} ^ {mode_b}) % {modulus} with exponent {exponent}");
+                let base = console::Integer::new(modulus - I::one());
+                let (exponent, modulus) = (console::Integer::new(exponent), console::Integer::new(modulus));
+                check_pow_mod::<I>(&name, base, exponent, modulus, mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_mod_halts() {
+        let one = console::Integer::<_, u8>::one();
+        let zero = console::Integer::<_, u8>::zero();
+
+        // Ensure a non-constant modulus halts.
+        let a = Integer::<Circuit, u8>::new(Mode::Private, zero);
+        let m = Integer::<Circuit, u8>::new(Mode::Private, one + one);
+        check_operation_halts(&a, &m, |a, m| a.pow_mod(a, m));
+
+        // Ensure a zero modulus halts.
+        let m = Integer::<Circuit, u8>::constant(zero);
+        check_operation_halts(&a, &m, |a, m| a.pow_mod(a, m));
+
+        // Ensure a signed integer type halts.
+        let a = Integer::<Circuit, i8>::new(Mode::Private, console::Integer::zero());
+        let m = Integer::<Circuit, i8>::constant(console::Integer::one());
+        check_operation_halts(&a, &m, |a, m| a.pow_mod(a, m));
+
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, u8, pow_mod);
+    test_integer_binary!(run_test, u16, pow_mod);
+    test_integer_binary!(run_test, u32, pow_mod);
+    test_integer_binary!(run_test, u64, pow_mod);
+    test_integer_binary!(run_test, u128, pow_mod);
+}
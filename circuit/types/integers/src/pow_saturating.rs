@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> PowSaturating<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self` to the power of `other`, saturating at the numeric bounds if an overflow occurs.
+    /// A negative base raised to an odd power saturates to `I::MIN`, and every other overflow saturates to `I::MAX`.
+    #[inline]
+    fn pow_saturating(&self, other: &Integer<E, M>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the result and return the new constant.
+            // This cast is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+            witness!(|self, other| console::Integer::new(match self.checked_pow(&other.to_u32().unwrap()) {
+                Some(value) => value,
+                None if *self < I::zero() && *other % (M::one() + M::one()) == M::one() => I::MIN,
+                None => I::MAX,
+            }))
+        } else {
+            let mut result = Self::one();
+            // Note: Once an intermediate square or product overflows, the result saturates, so the flag is sticky.
+            let mut is_saturated = Boolean::constant(false);
+
+            for bit in other.bits_le.iter().rev() {
+                let (square, square_overflows) = result.overflowing_mul(&result);
+                let (product, product_overflows) = square.overflowing_mul(self);
+
+                is_saturated = is_saturated | square_overflows | (product_overflows & bit);
+                result = Self::ternary(bit, &product, &square);
+            }
+
+            if I::is_signed() {
+                // Determine the saturation bound, which is `I::MIN` for a negative base raised to an odd power,
+                // and `I::MAX` otherwise.
+                let is_negative = self.msb() & &other.bits_le[0];
+                let mut bound_bits_le = vec![!&is_negative; I::BITS as usize - 1];
+                bound_bits_le.push(is_negative);
+                let bound = Integer { bits_le: bound_bits_le, phantom: Default::default() };
+
+                Self::ternary(&is_saturated, &bound, &result)
+            } else {
+                // For unsigned exponentiation, saturating to `I::MAX` is equivalent to setting every bit of the result.
+                Integer {
+                    bits_le: result.bits_le.iter().map(|bit| &is_saturated | bit).collect(),
+                    phantom: Default::default(),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use std::ops::RangeInclusive;
+
+    // Lowered to 4; we run (~5 * ITERATIONS) cases for most tests.
+    const ITERATIONS: u64 = 4;
+
+    fn check_pow<I: IntegerType, M: Magnitude>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        // Compute the expected result, saturating to `I::MIN` for a negative base raised to an odd power.
+        let expected = match first.checked_pow(&second.to_u32().unwrap()) {
+            Some(value) => value,
+            None if *first < I::zero() && second.to_u32().unwrap() % 2 == 1 => I::MIN,
+            None => I::MAX,
+        };
+        Circuit::scope(name, || {
+            let candidate = a.pow_saturating(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            match (mode_a, mode_b) {
+                (Mode::Constant, Mode::Constant) => assert_scope!(I::BITS, 0, 0, 0),
+                _ => assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)"),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType, M: Magnitude>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Pow: {mode_a} ** {mode_b} {i}");
+            check_pow::<I, M>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Pow Zero: {mode_a} ** {mode_b} {i}");
+            check_pow::<I, M>(&name, first, console::Integer::zero(), mode_a, mode_b);
+
+            let name = format!("Pow One: {mode_a} ** {mode_b} {i}");
+            check_pow::<I, M>(&name, first, console::Integer::one(), mode_a, mode_b);
+
+            let name = format!("Square: {mode_a} ** {mode_b} {i}");
+            check_pow::<I, M>(&name, first, console::Integer::one() + console::Integer::one(), mode_a, mode_b);
+        }
+
+        // Check exponents that overflow partway through, for positive and negative bases.
+        let two = console::Integer::<_, I>::one() + console::Integer::one();
+        let three = two + console::Integer::one();
+        for exponent in [I::BITS - 2, I::BITS - 1, I::BITS, I::BITS + 1] {
+            let exponent = console::Integer::<_, M>::new(M::from(exponent).unwrap());
+            check_pow::<I, M>("2 ** BITS", two, exponent, mode_a, mode_b);
+            check_pow::<I, M>("3 ** BITS", three, exponent, mode_a, mode_b);
+            if I::is_signed() {
+                check_pow::<I, M>("-2 ** BITS", -two, exponent, mode_a, mode_b);
+                check_pow::<I, M>("-3 ** BITS", -three, exponent, mode_a, mode_b);
+            }
+        }
+
+        // Test corner cases for exponentiation.
+        check_pow::<I, M>("MAX ** MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_pow::<I, M>("MIN ** MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        check_pow::<I, M>("MIN ** 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+        check_pow::<I, M>("MAX ** 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_pow::<I, M>("MIN ** 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_pow::<I, M>("MAX ** 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+    }
+
+    fn run_exhaustive_test<I: IntegerType, M: Magnitude>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+        RangeInclusive<M>: Iterator<Item = M>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in M::MIN..=M::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, M>::new(second);
+
+                let name = format!("Pow: ({first} ** {second})");
+                check_pow::<I, M>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, u8, pow_saturating);
+    test_integer_binary!(run_test, i8, u16, pow_saturating);
+    test_integer_binary!(run_test, i8, u32, pow_saturating);
+
+    test_integer_binary!(run_test, i16, u8, pow_saturating);
+    test_integer_binary!(run_test, i16, u16, pow_saturating);
+    test_integer_binary!(run_test, i16, u32, pow_saturating);
+
+    test_integer_binary!(run_test, i32, u8, pow_saturating);
+    test_integer_binary!(run_test, i32, u16, pow_saturating);
+    test_integer_binary!(run_test, i32, u32, pow_saturating);
+
+    test_integer_binary!(run_test, i64, u8, pow_saturating);
+    test_integer_binary!(run_test, i64, u16, pow_saturating);
+    test_integer_binary!(run_test, i64, u32, pow_saturating);
+
+    test_integer_binary!(run_test, i128, u8, pow_saturating);
+    test_integer_binary!(run_test, i128, u16, pow_saturating);
+    test_integer_binary!(run_test, i128, u32, pow_saturating);
+
+    test_integer_binary!(run_test, u8, u8, pow_saturating);
+    test_integer_binary!(run_test, u8, u16, pow_saturating);
+    test_integer_binary!(run_test, u8, u32, pow_saturating);
+
+    test_integer_binary!(run_test, u16, u8, pow_saturating);
+    test_integer_binary!(run_test, u16, u16, pow_saturating);
+    test_integer_binary!(run_test, u16, u32, pow_saturating);
+
+    test_integer_binary!(run_test, u32, u8, pow_saturating);
+    test_integer_binary!(run_test, u32, u16, pow_saturating);
+    test_integer_binary!(run_test, u32, u32, pow_saturating);
+
+    test_integer_binary!(run_test, u64, u8, pow_saturating);
+    test_integer_binary!(run_test, u64, u16, pow_saturating);
+    test_integer_binary!(run_test, u64, u32, pow_saturating);
+
+    test_integer_binary!(run_test, u128, u8, pow_saturating);
+    test_integer_binary!(run_test, u128, u16, pow_saturating);
+    test_integer_binary!(run_test, u128, u32, pow_saturating);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, pow_saturating, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, pow_saturating, exhaustive);
+}
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the smallest power of two greater than or equal to `self`.
+    /// This operation is only defined for unsigned integers, and halts if the result overflows.
+    pub fn next_power_of_two(&self) -> Self {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("The next power of two is only defined for unsigned integers")
+        }
+
+        // Set every bit below the most significant bit of `self - 1`, and add one.
+        // Note: `monus` is used so that zero maps to one, as in the console implementation.
+        // Note: This addition must be checked, as the result overflows if `self` exceeds the largest power of two.
+        self.monus(&Self::one()).smear_right().add_checked(&Self::one())
+    }
+
+    /// Returns the largest power of two less than or equal to `self`, or zero if `self` is zero.
+    /// This operation is only defined for unsigned integers.
+    pub fn prev_power_of_two(&self) -> Self {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("The previous power of two is only defined for unsigned integers")
+        }
+
+        // Set every bit below the most significant bit of `self`.
+        let smeared = self.smear_right();
+
+        // Retain only the most significant bit, which is the only bit that differs from the next higher bit.
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        for (i, bit) in smeared.bits_le.iter().enumerate() {
+            match smeared.bits_le.get(i + 1) {
+                Some(next_bit) => bits_le.push(bit & !next_bit),
+                None => bits_le.push(bit.clone()),
+            }
+        }
+        Integer { bits_le, phantom: Default::default() }
+    }
+
+    /// Returns a copy of `self`, where every bit below the most significant set bit is set.
+    pub(super) fn smear_right(&self) -> Self {
+        // Compute the prefix-OR of the bits, starting from the most significant bit.
+        let mut bits_le = self.bits_le.clone();
+        for i in (0..bits_le.len().saturating_sub(1)).rev() {
+            bits_le[i] = &bits_le[i] | &bits_le[i + 1];
+        }
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::UnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_next_power_of_two<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Compute the expected value, where `0` maps to `1`.
+        let mut expected = Some(I::one());
+        while let Some(power) = expected {
+            if power >= *value {
+                break;
+            }
+            expected = power.checked_mul(&(I::one() + I::one()));
+        }
+
+        match expected {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.next_power_of_two();
+                assert_eq!(expected, *candidate.eject_value());
+                assert_scope!();
+            }),
+            None => match mode {
+                Mode::Constant => check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.next_power_of_two()),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.next_power_of_two();
+                    assert!(!Circuit::is_satisfied_in_scope());
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn check_prev_power_of_two<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = match value.is_zero() {
+            true => I::zero(),
+            false => I::one() << (I::BITS as usize - 1 - value.leading_zeros() as usize),
+        };
+
+        Circuit::scope(name, || {
+            let candidate = a.prev_power_of_two();
+            assert_eq!(expected, *candidate.eject_value());
+            match mode {
+                Mode::Constant => assert_scope!(0, 0, 0, 0),
+                _ => assert_scope!(0, 0, 2 * I::BITS - 2, 2 * I::BITS - 2),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+
+            let name = format!("PowerOfTwo: {mode} {i}");
+            check_next_power_of_two::<I>(&name, value, mode);
+            check_prev_power_of_two::<I>(&name, value, mode);
+        }
+
+        // Check the zero, one, and bounds cases.
+        for value in [console::Integer::zero(), console::Integer::one(), console::Integer::MAX] {
+            let name = format!("PowerOfTwo: {mode} {value}");
+            check_next_power_of_two::<I>(&name, value, mode);
+            check_prev_power_of_two::<I>(&name, value, mode);
+        }
+
+        // Check that exact powers of two map to themselves, and their neighbors round correctly.
+        for shift in 0..I::BITS as usize {
+            let power = I::one() << shift;
+            for value in [power, power.wrapping_sub(&I::one()), power.wrapping_add(&I::one())] {
+                let name = format!("PowerOfTwo: {mode} {value}");
+                check_next_power_of_two::<I>(&name, console::Integer::new(value), mode);
+                check_prev_power_of_two::<I>(&name, console::Integer::new(value), mode);
+            }
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + UnwindSafe>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("PowerOfTwo: {mode} {value}");
+            check_next_power_of_two::<I>(&name, value, mode);
+            check_prev_power_of_two::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, u8, power_of_two);
+    test_integer_unary!(run_test, u16, power_of_two);
+    test_integer_unary!(run_test, u32, power_of_two);
+    test_integer_unary!(run_test, u64, power_of_two);
+    test_integer_unary!(run_test, u128, power_of_two);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, power_of_two, exhaustive);
+}
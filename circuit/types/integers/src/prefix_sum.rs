@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the inclusive prefix sums of `values`, i.e. `[v[0], v[0] + v[1], ..., v[0] + ... + v[n - 1]]`.
+    /// The output has the same length as `values`, and is empty if `values` is empty.
+    ///
+    /// Each sum is computed with `add_checked` from the previous one, so the number of constraints grows
+    /// linearly in the length of `values`, and this halts (or is unsatisfiable) at the first sum that overflows.
+    pub fn prefix_sum(values: &[Self]) -> Vec<Self> {
+        let mut sums: Vec<Self> = Vec::with_capacity(values.len());
+        for value in values {
+            let sum = match sums.last() {
+                Some(previous) => previous.add_checked(value),
+                None => value.clone(),
+            };
+            sums.push(sum);
+        }
+        sums
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const MAX_LENGTH: usize = 8;
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sample values small enough that the prefix sums cannot overflow.
+        let (min, max) = match I::is_signed() {
+            true => (I::from(-8).unwrap(), I::from(8).unwrap()),
+            false => (I::zero(), I::from(8).unwrap()),
+        };
+
+        for length in 0..=MAX_LENGTH {
+            let values: Vec<_> =
+                (0..length).map(|_| Integer::<Circuit, I>::rand_bounded(mode, &mut rng, min, max)).collect();
+
+            // Compute the expected prefix sums with a reference scan over the primitives.
+            let expected: Vec<I> = values
+                .iter()
+                .scan(I::zero(), |sum, value| {
+                    *sum = *sum + *value.eject_value();
+                    Some(*sum)
+                })
+                .collect();
+
+            Circuit::scope(format!("PrefixSum: {mode} {length}"), || {
+                let candidate = Integer::prefix_sum(&values);
+                assert_eq!(length, candidate.len());
+                assert_eq!(expected, candidate.iter().map(|sum| *sum.eject_value()).collect::<Vec<_>>());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+
+        // Ensure an overflowing prefix sum halts for constants, and is unsatisfiable otherwise.
+        let values =
+            [Integer::<Circuit, I>::new(mode, console::Integer::MAX), Integer::new(mode, console::Integer::one())];
+        match mode.is_constant() {
+            true => check_unary_operation_halts(&values[..], Integer::prefix_sum),
+            false => {
+                let _candidate = Integer::prefix_sum(&values);
+                assert!(!Circuit::is_satisfied());
+            }
+        }
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, prefix_sum);
+    test_integer_unary!(run_test, i16, prefix_sum);
+    test_integer_unary!(run_test, i32, prefix_sum);
+    test_integer_unary!(run_test, i64, prefix_sum);
+    test_integer_unary!(run_test, i128, prefix_sum);
+
+    test_integer_unary!(run_test, u8, prefix_sum);
+    test_integer_unary!(run_test, u16, prefix_sum);
+    test_integer_unary!(run_test, u32, prefix_sum);
+    test_integer_unary!(run_test, u64, prefix_sum);
+    test_integer_unary!(run_test, u128, prefix_sum);
+}
@@ -84,23 +84,12 @@ impl<E: Environment, I: IntegerType> RemChecked<Self> for Integer<E, I> {
                     let neg_one = Integer::constant(-console::Integer::one());
                     let overflows = self.is_equal(&min) & other.is_equal(&neg_one);
                     E::assert(!overflows);
-
-                    // Divide the absolute value of `self` and `other` in the base field.
-                    let unsigned_dividend = self.abs_wrapped().cast_as_dual();
-                    // Note that `unsigned_divisor` is zero iff `other` is zero.
-                    let unsigned_divisor = other.abs_wrapped().cast_as_dual();
-                    // Note that this call to `rem_wrapped` checks that `unsigned_divisor` is not zero.
-                    let unsigned_remainder = unsigned_dividend.rem_wrapped(&unsigned_divisor);
-
-                    let signed_remainder = Self { bits_le: unsigned_remainder.bits_le, phantom: Default::default() };
-
-                    // The remainder takes on the same sign as `self` because the division operation rounds towards zero.
-                    Self::ternary(&!self.msb(), &signed_remainder, &Self::zero().sub_wrapped(&signed_remainder))
-                } else {
-                    // Return the remainder of `self` and `other`.
-                    // Note that this call to `rem_wrapped` checks that `unsigned_divisor` is not zero.
-                    self.rem_wrapped(other)
                 }
+
+                // Return the remainder of `self` and `other`.
+                // Note that this call to `rem_wrapped` shares the division gadget with `div_wrapped`,
+                // and checks that the divisor is not zero.
+                self.rem_wrapped(other)
             }
         }
     }
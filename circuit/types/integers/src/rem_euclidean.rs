@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> RemEuclidean<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn rem_euclidean(&self, other: &Integer<E, I>) -> Self::Output {
+        match I::is_signed() {
+            true => {
+                // Compute the truncated remainder of `self` and `other`, which takes on the sign of `self`.
+                // Note that this call halts, or fails to satisfy the circuit, on division by zero and on overflow.
+                let remainder = self.rem_checked(other);
+
+                // The truncated remainder is negative if and only if `self` is negative and not divisible by `other`.
+                // In this case, the remainder is shifted into `0..|other|` by adding the absolute value of `other`.
+                // Note: it is safe to use `abs_wrapped` and `add_wrapped`, as `Integer::MIN` is interpreted as an
+                // unsigned number, and the adjusted remainder is always less than `|Integer::MIN|`.
+                let adjusted_remainder = remainder.add_wrapped(&other.abs_wrapped());
+
+                Self::ternary(remainder.msb(), &adjusted_remainder, &remainder)
+            }
+            // For unsigned integers, the Euclidean remainder is equivalent to the truncated remainder.
+            false => self.rem_checked(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rem_euclidean<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        match (*first).checked_rem(&*second) {
+            Some(remainder) => {
+                let expected = match remainder < I::zero() {
+                    true if *second < I::zero() => remainder - *second,
+                    true => remainder + *second,
+                    false => remainder,
+                };
+                Circuit::scope(name, || {
+                    let candidate = a.rem_euclidean(&b);
+                    assert_eq!(expected, *candidate.eject_value());
+                    assert_eq!(console::Integer::new(expected), candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                })
+            }
+            None => match (mode_a, mode_b) {
+                (_, Mode::Constant) if second == console::Integer::zero() => {
+                    check_operation_halts(&a, &b, Integer::rem_euclidean)
+                }
+                (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::rem_euclidean),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.rem_euclidean(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("RemEuclidean: {mode_a} % {mode_b} {i}");
+            check_rem_euclidean::<I>(&name, first, second, mode_a, mode_b);
+            check_rem_euclidean::<I>(&name, second, first, mode_a, mode_b);
+
+            let name = format!("RemEuclidean by Zero: {mode_a} % {mode_b} {i}");
+            check_rem_euclidean::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+        }
+
+        // Check standard properties and corner cases.
+        check_rem_euclidean::<I>("MAX % 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_rem_euclidean::<I>("MIN % 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_rem_euclidean::<I>("MIN % MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        check_rem_euclidean::<I>("MAX % 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+
+        // Check the signed corner cases.
+        if I::is_signed() {
+            let three = console::Integer::one() + console::Integer::one() + console::Integer::one();
+            let seven = three + three + console::Integer::one();
+            check_rem_euclidean::<I>("-7 % 3", -seven, three, mode_a, mode_b);
+            check_rem_euclidean::<I>("-7 % -3", -seven, -three, mode_a, mode_b);
+            check_rem_euclidean::<I>("7 % -3", seven, -three, mode_a, mode_b);
+            check_rem_euclidean::<I>("MIN % -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_rem_euclidean::<I>("-1 % MIN", -console::Integer::one(), console::Integer::MIN, mode_a, mode_b);
+            check_rem_euclidean::<I>("MAX % MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("RemEuclidean: ({first} % {second})");
+                check_rem_euclidean::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, rem_euclidean);
+    test_integer_binary!(run_test, i16, rem_euclidean);
+    test_integer_binary!(run_test, i32, rem_euclidean);
+    test_integer_binary!(run_test, i64, rem_euclidean);
+    test_integer_binary!(run_test, i128, rem_euclidean);
+
+    test_integer_binary!(run_test, u8, rem_euclidean);
+    test_integer_binary!(run_test, u16, rem_euclidean);
+    test_integer_binary!(run_test, u32, rem_euclidean);
+    test_integer_binary!(run_test, u64, rem_euclidean);
+    test_integer_binary!(run_test, u128, rem_euclidean);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, rem_euclidean, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, rem_euclidean, exhaustive);
+}
@@ -163,6 +163,48 @@ mod tests {
         }
     }
 
+    fn check_div_rem_identity<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        Circuit::scope(name, || {
+            // Check that `a == (a / b) * b + (a % b)`, using wrapped arithmetic throughout.
+            let quotient = a.div_wrapped(&b);
+            let remainder = a.rem_wrapped(&b);
+            let candidate = quotient.mul_wrapped(&b).add_wrapped(&remainder);
+            assert_eq!(first, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_identity_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = match Uniform::rand(&mut rng) {
+                divisor if divisor == console::Integer::zero() => console::Integer::one(),
+                divisor => divisor,
+            };
+
+            let name = format!("DivRemIdentity: {mode_a} % {mode_b} {i}");
+            check_div_rem_identity::<I>(&name, first, second, mode_a, mode_b);
+        }
+
+        check_div_rem_identity::<I>("MAX % 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_div_rem_identity::<I>("MIN % MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+
+        if I::is_signed() {
+            check_div_rem_identity::<I>("MIN % -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+        }
+    }
+
     fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
     where
         RangeInclusive<I>: Iterator<Item = I>,
@@ -190,6 +232,18 @@ mod tests {
     test_integer_binary!(run_test, u64, rem);
     test_integer_binary!(run_test, u128, rem);
 
+    test_integer_binary!(run_identity_test, i8, rem_identity);
+    test_integer_binary!(run_identity_test, i16, rem_identity);
+    test_integer_binary!(run_identity_test, i32, rem_identity);
+    test_integer_binary!(run_identity_test, i64, rem_identity);
+    test_integer_binary!(run_identity_test, i128, rem_identity);
+
+    test_integer_binary!(run_identity_test, u8, rem_identity);
+    test_integer_binary!(run_identity_test, u16, rem_identity);
+    test_integer_binary!(run_identity_test, u32, rem_identity);
+    test_integer_binary!(run_identity_test, u64, rem_identity);
+    test_integer_binary!(run_identity_test, u128, rem_identity);
+
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, rem, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, rem, exhaustive);
 }
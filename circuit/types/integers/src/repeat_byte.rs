@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes a new integer in which every byte equals `byte`, e.g. `0x0101_0101` for `0x01` as a `u32`.
+    ///
+    /// The eight bits of `byte` are injected once, with the given mode, and then replicated across the integer.
+    /// As a result, the bytes of the integer are equal by construction, and a non-constant integer costs
+    /// 8 variables and 8 constraints, regardless of its bit width.
+    pub fn from_repeated_byte(mode: Mode, byte: u8) -> Self {
+        let byte_le = (0..8).map(|i| Boolean::new(mode, (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let bits_le = byte_le.iter().cycle().take(I::BITS as usize).cloned().collect();
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 64;
+
+    fn check_from_repeated_byte<I: IntegerType>(name: &str, byte: u8, mode: Mode) {
+        // Compute the expected value by repeating the bits of the byte.
+        let byte_le = (0..8).map(|i| (byte >> i) & 1 == 1).collect::<Vec<_>>();
+        let bits_le = byte_le.iter().copied().cycle().take(I::BITS as usize).collect::<Vec<_>>();
+        let expected = console::Integer::<<Circuit as Environment>::Network, I>::from_bits_le(&bits_le).unwrap();
+        Circuit::scope(name, || {
+            let candidate = Integer::<Circuit, I>::from_repeated_byte(mode, byte);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(mode, candidate.eject_mode());
+            match mode {
+                Mode::Constant => assert_scope!(8, 0, 0, 0),
+                Mode::Public => assert_scope!(0, 8, 0, 8),
+                Mode::Private => assert_scope!(0, 0, 8, 8),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("FromRepeatedByte: {mode} {i}");
+            check_from_repeated_byte::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        for byte in [0x00, 0x01, 0x7f, 0x80, 0xaa, 0xff] {
+            let name = format!("FromRepeatedByte: {mode} {byte:#04x}");
+            check_from_repeated_byte::<I>(&name, byte, mode);
+        }
+    }
+
+    #[test]
+    fn test_from_repeated_byte_matches_primitive() {
+        let candidate = Integer::<Circuit, u32>::from_repeated_byte(Mode::Private, 0x01);
+        assert_eq!(0x0101_0101u32, *candidate.eject_value());
+        let candidate = Integer::<Circuit, u64>::from_repeated_byte(Mode::Public, 0xa5);
+        assert_eq!(0xa5a5_a5a5_a5a5_a5a5u64, *candidate.eject_value());
+        let candidate = Integer::<Circuit, i16>::from_repeated_byte(Mode::Constant, 0xff);
+        assert_eq!(-1i16, *candidate.eject_value());
+        let candidate = Integer::<Circuit, i8>::from_repeated_byte(Mode::Private, 0x80);
+        assert_eq!(i8::MIN, *candidate.eject_value());
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, from_repeated_byte);
+    test_integer_unary!(run_test, i16, from_repeated_byte);
+    test_integer_unary!(run_test, i32, from_repeated_byte);
+    test_integer_unary!(run_test, i64, from_repeated_byte);
+    test_integer_unary!(run_test, i128, from_repeated_byte);
+
+    test_integer_unary!(run_test, u8, from_repeated_byte);
+    test_integer_unary!(run_test, u16, from_repeated_byte);
+    test_integer_unary!(run_test, u32, from_repeated_byte);
+    test_integer_unary!(run_test, u64, from_repeated_byte);
+    test_integer_unary!(run_test, u128, from_repeated_byte);
+}
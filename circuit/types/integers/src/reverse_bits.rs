@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> ReverseBits for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self` with the order of its bits reversed.
+    fn reverse_bits(&self) -> Self::Output {
+        // Note: Interpreting the little-endian bits as big-endian bits reverses their order, without any constraints.
+        Integer::from_bits_be(&self.bits_le)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn ReverseBits<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn ReverseBits<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        *case
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_reverse_bits<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = (*value).reverse_bits();
+        Circuit::scope(name, || {
+            let candidate = a.reverse_bits();
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(ReverseBits(Integer<I>) => Integer<I>, &mode);
+            assert_output_mode!(ReverseBits(Integer<I>) => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("ReverseBits: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_reverse_bits::<I>(&name, value, mode);
+        }
+
+        check_reverse_bits::<I>(&format!("ReverseBits: {mode} zero"), console::Integer::zero(), mode);
+        check_reverse_bits::<I>(&format!("ReverseBits: {mode} one"), console::Integer::one(), mode);
+        check_reverse_bits::<I>(&format!("ReverseBits: {mode} max"), console::Integer::MAX, mode);
+        check_reverse_bits::<I>(&format!("ReverseBits: {mode} min"), console::Integer::MIN, mode);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("ReverseBits: {mode}");
+            check_reverse_bits::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, reverse_bits);
+    test_integer_unary!(run_test, i16, reverse_bits);
+    test_integer_unary!(run_test, i32, reverse_bits);
+    test_integer_unary!(run_test, i64, reverse_bits);
+    test_integer_unary!(run_test, i128, reverse_bits);
+
+    test_integer_unary!(run_test, u8, reverse_bits);
+    test_integer_unary!(run_test, u16, reverse_bits);
+    test_integer_unary!(run_test, u32, reverse_bits);
+    test_integer_unary!(run_test, u64, reverse_bits);
+    test_integer_unary!(run_test, u128, reverse_bits);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, reverse_bits, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, reverse_bits, exhaustive);
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> RotateLeft for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self` with its bits rotated to the left by `n` (modulo the bitwidth).
+    fn rotate_left(&self, n: u32) -> Self::Output {
+        // Note: Rotating the value to the left moves each bit towards the end of the little-endian bits,
+        // which is a reindexing of the bits, and requires no constraints.
+        let mut bits_le = self.bits_le.clone();
+        bits_le.rotate_right((n as u64 % I::BITS) as usize);
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn RotateLeft<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn RotateLeft<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        *case
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_rotate_left<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        n: u32,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = (*value).rotate_left(n);
+        Circuit::scope(name, || {
+            let candidate = a.rotate_left(n);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(RotateLeft(Integer<I>) => Integer<I>, &mode);
+            assert_output_mode!(RotateLeft(Integer<I>) => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            let n = u32::rand(&mut rng) % (2 * I::BITS as u32 + 1);
+
+            let name = format!("RotateLeft: {mode} << {n} {i}");
+            check_rotate_left::<I>(&name, value, n, mode);
+
+            // Check the rotations by zero and by the bitwidth, which are the identity.
+            let name = format!("RotateLeft: {mode} << 0 {i}");
+            check_rotate_left::<I>(&name, value, 0, mode);
+            let name = format!("RotateLeft: {mode} << BITS {i}");
+            check_rotate_left::<I>(&name, value, I::BITS as u32, mode);
+        }
+
+        check_rotate_left::<I>(&format!("RotateLeft: {mode} one << 1"), console::Integer::one(), 1, mode);
+        check_rotate_left::<I>(&format!("RotateLeft: {mode} MIN << 1"), console::Integer::MIN, 1, mode);
+        check_rotate_left::<I>(&format!("RotateLeft: {mode} MAX << 1"), console::Integer::MAX, 1, mode);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            for n in 0..=I::BITS as u32 {
+                let name = format!("RotateLeft: {mode} << {n}");
+                check_rotate_left::<I>(&name, value, n, mode);
+            }
+        }
+    }
+
+    test_integer_unary!(run_test, i8, rotate_left);
+    test_integer_unary!(run_test, i16, rotate_left);
+    test_integer_unary!(run_test, i32, rotate_left);
+    test_integer_unary!(run_test, i64, rotate_left);
+    test_integer_unary!(run_test, i128, rotate_left);
+
+    test_integer_unary!(run_test, u8, rotate_left);
+    test_integer_unary!(run_test, u16, rotate_left);
+    test_integer_unary!(run_test, u32, rotate_left);
+    test_integer_unary!(run_test, u64, rotate_left);
+    test_integer_unary!(run_test, u128, rotate_left);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, rotate_left, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, rotate_left, exhaustive);
+}
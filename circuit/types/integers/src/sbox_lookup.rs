@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Integer<E, u8> {
+    /// Returns the entry of the given 256-entry `table` at the index `self`.
+    ///
+    /// The lookup is computed as a multilinear interpolation of the table over the bits of `self`,
+    /// which requires far fewer constraints than selecting among all 256 entries with `ternary`.
+    pub fn sbox_lookup(&self, table: &[u8; 256]) -> U8<E> {
+        // Initialize the table entries as constant field elements.
+        let mut entries =
+            table.iter().map(|entry| Field::constant(console::Field::from_u8(*entry))).collect::<Vec<_>>();
+
+        // Halve the entries with each bit of `self`, starting from the least significant bit.
+        // At each step, the entry at index `i` is selected from the entries at indices `2i` and `2i + 1`.
+        // Note: The first step is free of constraints, as the selected entries are constant.
+        for bit in &self.bits_le {
+            entries = entries.chunks(2).map(|pair| Field::ternary(bit, &pair[1], &pair[0])).collect();
+        }
+
+        // Return the selected entry as an integer.
+        // Note: This is safe as every entry in the table is a valid `u8`.
+        match entries.pop() {
+            Some(entry) => U8::from_field(entry),
+            // Note: `E::halt` should never be invoked as the table contains 2^8 entries.
+            None => E::halt("Malformed table detected during the S-box lookup"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_sbox_lookup(mode: Mode, table: &[u8; 256]) {
+        for index in 0..=u8::MAX {
+            let candidate = U8::<Circuit>::new(mode, console::Integer::new(index));
+
+            Circuit::scope(format!("SboxLookup {mode} {index}"), || {
+                let candidate = candidate.sbox_lookup(table);
+                assert_eq!(table[index as usize], *candidate.eject_value());
+                // Note: The counts are upper bounds, as equal adjacent entries reduce the number of constraints.
+                match mode {
+                    Mode::Constant => assert_scope!(<=264, 0, 0, 0),
+                    _ => {
+                        assert_eq!(0, Circuit::num_public_in_scope());
+                        assert!(Circuit::num_private_in_scope() <= 135);
+                        assert!(Circuit::num_constraints_in_scope() <= 136);
+                        assert_scope!();
+                    }
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_sbox_lookup() {
+        let mut rng = TestRng::default();
+
+        // Sample a random table.
+        let mut table = [0u8; 256];
+        table.iter_mut().for_each(|entry| *entry = Uniform::rand(&mut rng));
+
+        check_sbox_lookup(Mode::Constant, &table);
+        check_sbox_lookup(Mode::Public, &table);
+        check_sbox_lookup(Mode::Private, &table);
+    }
+
+    #[test]
+    fn test_sbox_lookup_identity() {
+        // Initialize the identity table.
+        let mut table = [0u8; 256];
+        table.iter_mut().enumerate().for_each(|(index, entry)| *entry = index as u8);
+
+        check_sbox_lookup(Mode::Constant, &table);
+        check_sbox_lookup(Mode::Public, &table);
+        check_sbox_lookup(Mode::Private, &table);
+    }
+}
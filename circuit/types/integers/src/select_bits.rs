@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> SelectBits for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `(first & mask) | (second & !mask)`, i.e. each bit is taken from `first` if the corresponding bit
+    /// of `mask` is set, and from `second` otherwise.
+    ///
+    /// This costs a single ternary per bit, i.e. `I::BITS` constraints, instead of `3 * I::BITS` constraints
+    /// for the equivalent bitwise formula.
+    fn select_bits(mask: &Self, first: &Self, second: &Self) -> Self::Output {
+        // Directly instantiate the integer, rather than invoking `from_bits_le`,
+        // since the mode of each bit depends on the modes and values of the corresponding input bits.
+        Self {
+            bits_le: mask
+                .bits_le
+                .iter()
+                .zip_eq(first.bits_le.iter().zip_eq(second.bits_le.iter()))
+                .map(|(mask_bit, (first_bit, second_bit))| Boolean::ternary(mask_bit, first_bit, second_bit))
+                .collect(),
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn SelectBits<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _, _) | (_, Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            _ => Count::is(0, 0, I::BITS, I::BITS),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn SelectBits<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (CircuitType<Integer<E, I>>, Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (mask, mode_a, mode_b) = case;
+        match mask {
+            // Determine if the constant mask selects all bits from one operand.
+            CircuitType::Constant(constant) => {
+                let mask = constant.eject_value();
+                match (mask.is_zero(), (!mask).is_zero()) {
+                    (true, _) => *mode_b,
+                    (_, true) => *mode_a,
+                    _ => Mode::combine(*mode_a, [*mode_b]),
+                }
+            }
+            _ => match mode_a.is_constant() && mode_b.is_constant() {
+                true => E::halt("The output mode of a variable mask over constants depends on their values."),
+                false => Mode::Private,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_select_bits<I: IntegerType>(
+        name: &str,
+        mask: console::Integer<<Circuit as Environment>::Network, I>,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_mask: Mode,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let m = Integer::<Circuit, I>::new(mode_mask, mask);
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let expected = (first & mask) | (second & !mask);
+        Circuit::scope(name, || {
+            let candidate = Integer::select_bits(&m, &a, &b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Integer<Circuit, I>, SelectBits<Output = Integer<Circuit, I>>, &(mode_mask, mode_a, mode_b));
+            // Note: The output mode of a variable mask over constant operands depends on their values.
+            if mode_mask.is_constant() || !(mode_a.is_constant() && mode_b.is_constant()) {
+                assert_output_mode!(Integer<Circuit, I>, SelectBits<Output = Integer<Circuit, I>>, &(CircuitType::from(&m), mode_a, mode_b), candidate);
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_mask: Mode, mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let mask = Uniform::rand(&mut rng);
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("SelectBits: ({mode_mask}, {mode_a}, {mode_b}) {i}");
+            check_select_bits::<I>(&name, mask, first, second, mode_mask, mode_a, mode_b);
+        }
+
+        // Check that a mask of all zeros or all ones selects `second` or `first`, respectively.
+        let all_ones = !console::Integer::zero();
+        let (zero, max, min) = (console::Integer::zero(), console::Integer::MAX, console::Integer::MIN);
+        check_select_bits::<I>("SelectBits: (0, MAX, MIN)", zero, max, min, mode_mask, mode_a, mode_b);
+        check_select_bits::<I>("SelectBits: (1s, MAX, MIN)", all_ones, max, min, mode_mask, mode_a, mode_b);
+    }
+
+    test_integer_ternary!(run_test, i8, select, from, else);
+    test_integer_ternary!(run_test, i16, select, from, else);
+    test_integer_ternary!(run_test, i32, select, from, else);
+    test_integer_ternary!(run_test, i64, select, from, else);
+    test_integer_ternary!(run_test, i128, select, from, else);
+
+    test_integer_ternary!(run_test, u8, select, from, else);
+    test_integer_ternary!(run_test, u16, select, from, else);
+    test_integer_ternary!(run_test, u32, select, from, else);
+    test_integer_ternary!(run_test, u64, select, from, else);
+    test_integer_ternary!(run_test, u128, select, from, else);
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the element of `elements` at position `index`, or zero if `index` is out of range.
+    ///
+    /// This builds a one-hot mask by comparing `index` against each position, and combines the masked elements.
+    /// As at most one element is selected, the masked elements are combined with a bitwise OR, which is equivalent
+    /// to (and cheaper than) their sum. The cost scales linearly with the number of elements.
+    pub fn select_index(elements: &[Self], index: &U32<E>) -> Self {
+        elements.iter().enumerate().fold(Self::zero(), |selected, (i, element)| {
+            // Determine if the position matches the index.
+            let is_selected = index.is_equal(&U32::constant(console::Integer::new(i as u32)));
+            // Mask the element, and combine it with the running selection.
+            selected | Self::ternary(&is_selected, element, &Self::zero())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const MAX_LENGTH: usize = 8;
+
+    fn check_select_index<I: IntegerType>(
+        name: &str,
+        values: &[console::Integer<<Circuit as Environment>::Network, I>],
+        index: u32,
+        mode_elements: Mode,
+        mode_index: Mode,
+    ) -> u64 {
+        let elements: Vec<_> = values.iter().map(|value| Integer::<Circuit, I>::new(mode_elements, *value)).collect();
+        let selector = U32::<Circuit>::new(mode_index, console::Integer::new(index));
+        let expected = values.get(index as usize).copied().unwrap_or_else(console::Integer::zero);
+        let num_constraints = Circuit::scope(name, || {
+            let candidate = Integer::select_index(&elements, &selector);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            // A constant index selects the element directly.
+            if mode_index.is_constant() {
+                assert_eq!(0, Circuit::num_constraints_in_scope());
+            }
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::reset();
+        num_constraints
+    }
+
+    fn run_test<I: IntegerType>(mode_elements: Mode, mode_index: Mode) {
+        let mut rng = TestRng::default();
+
+        let mut costs = Vec::with_capacity(MAX_LENGTH);
+        for length in 1..=MAX_LENGTH {
+            let values: Vec<_> = (0..length).map(|_| Uniform::rand(&mut rng)).collect();
+
+            // Select each in-range position, and ensure the cost is independent of the position.
+            let mut cost = None;
+            for index in 0..length as u32 {
+                let name = format!("SelectIndex: {mode_elements} [{index}] {mode_index} of {length}");
+                let candidate_cost = check_select_index::<I>(&name, &values, index, mode_elements, mode_index);
+                assert_eq!(*cost.get_or_insert(candidate_cost), candidate_cost);
+            }
+            costs.push(cost.unwrap());
+
+            // Ensure an out-of-range index selects zero.
+            for index in [length as u32, length as u32 + 1, u32::MAX] {
+                let name = format!("SelectIndex: {mode_elements} [{index}] {mode_index} of {length} (out of range)");
+                check_select_index::<I>(&name, &values, index, mode_elements, mode_index);
+            }
+        }
+
+        // Ensure the cost scales linearly with the number of elements.
+        // Note: For constant elements, the cost depends on their values, as the masked zero bits are free.
+        if !mode_elements.is_constant() {
+            let increments: Vec<_> = costs.windows(2).map(|pair| pair[1] - pair[0]).collect();
+            assert!(increments.iter().all(|increment| *increment == increments[0]), "Costs are not linear: {costs:?}");
+        }
+
+        // Ensure selecting from an empty slice returns zero.
+        let selector = U32::<Circuit>::new(mode_index, console::Integer::zero());
+        assert_eq!(console::Integer::zero(), Integer::<Circuit, I>::select_index(&[], &selector).eject_value());
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, i8, select_index);
+    test_integer_binary!(run_test, i16, select_index);
+    test_integer_binary!(run_test, i32, select_index);
+    test_integer_binary!(run_test, i64, select_index);
+    test_integer_binary!(run_test, i128, select_index);
+
+    test_integer_binary!(run_test, u8, select_index);
+    test_integer_binary!(run_test, u16, select_index);
+    test_integer_binary!(run_test, u32, select_index);
+    test_integer_binary!(run_test, u64, select_index);
+    test_integer_binary!(run_test, u128, select_index);
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self << amount`, clamping the result to `I::MAX` or `I::MIN` if the shift overflows.
+    /// A nonzero `self` saturates for any `amount` that is greater than or equal to the bitwidth.
+    pub fn shl_saturating(&self, amount: &U32<E>) -> Self {
+        // Determine if the shift amount is greater than or equal to the bitwidth.
+        let is_large_amount = amount.is_greater_than_or_equal(&U32::constant(console::U32::new(I::BITS as u32)));
+
+        // Compute the wrapped shift.
+        // Note: `shl_wrapped` and `shr_wrapped` only consider the lower bits of `amount`, which are less than `I::BITS`.
+        let wrapped = self.shl_wrapped(amount);
+
+        // The shift overflows if the wrapped shift does not round trip, or if a nonzero value is shifted by a large amount.
+        // Note: For signed integers, `shr_wrapped` is an arithmetic shift, so the round trip also detects a change in sign.
+        let is_not_round_trip = wrapped.shr_wrapped(amount).is_not_equal(self);
+        let is_overflow = is_not_round_trip | (is_large_amount & self.is_not_equal(&Self::zero()));
+
+        // Determine the saturated value, which matches the sign of `self`.
+        let saturated = match I::is_signed() {
+            true => Self::ternary(
+                self.msb(),
+                &Self::constant(console::Integer::MIN),
+                &Self::constant(console::Integer::MAX),
+            ),
+            false => Self::constant(console::Integer::MAX),
+        };
+
+        Self::ternary(&is_overflow, &saturated, &wrapped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_shl_saturating<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::U32<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        // Compute the expected value as the clamped product of `first` and `2^second`.
+        let expected = match first.is_zero() {
+            true => I::zero(),
+            false => match (*first).checked_shl(&second) {
+                Some(value) => value,
+                None if *first < I::zero() => I::MIN,
+                None => I::MAX,
+            },
+        };
+
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = U32::<Circuit>::new(mode_b, second);
+        Circuit::scope(name, || {
+            let candidate = a.shl_saturating(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_scope!();
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+
+            // Check a random shift amount, which almost always exceeds the bitwidth.
+            let second = Uniform::rand(&mut rng);
+            let name = format!("ShlSaturating: {mode_a} << {mode_b} {i}");
+            check_shl_saturating::<I>(&name, first, second, mode_a, mode_b);
+
+            // Check a shift amount within twice the bitwidth.
+            let second = console::U32::new(u32::rand(&mut rng) % (2 * I::BITS as u32));
+            let name = format!("ShlSaturating: {mode_a} << {mode_b} {second} {i}");
+            check_shl_saturating::<I>(&name, first, second, mode_a, mode_b);
+        }
+
+        // Check every shift amount up to the bitwidth, on small, large, and boundary values.
+        for shift in 0..=I::BITS as u32 {
+            let second = console::U32::new(shift);
+            let half = I::MAX >> (I::BITS as usize / 2);
+            for value in [I::zero(), I::one(), half, I::MAX, I::MIN, I::zero().wrapping_sub(&I::one())] {
+                let name = format!("ShlSaturating: {mode_a} {value} << {mode_b} {shift}");
+                check_shl_saturating::<I>(&name, console::Integer::new(value), second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, shl_saturating);
+    test_integer_binary!(run_test, i16, shl_saturating);
+    test_integer_binary!(run_test, i32, shl_saturating);
+    test_integer_binary!(run_test, i64, shl_saturating);
+    test_integer_binary!(run_test, i128, shl_saturating);
+
+    test_integer_binary!(run_test, u8, shl_saturating);
+    test_integer_binary!(run_test, u16, shl_saturating);
+    test_integer_binary!(run_test, u32, shl_saturating);
+    test_integer_binary!(run_test, u64, shl_saturating);
+    test_integer_binary!(run_test, u128, shl_saturating);
+}
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self >> rhs`, halting (or becoming unsatisfiable) if `rhs >= I::BITS`,
+    /// in the same manner as `shr_checked`.
+    ///
+    /// This is implemented as a barrel shifter: for each of the lower `log2(I::BITS)` bits of `rhs`,
+    /// the intermediate result is conditionally shifted by the corresponding power of two, using `ternary`.
+    /// Signed integers are shifted arithmetically (filling with the sign bit), and unsigned integers logically.
+    ///
+    /// For non-constant operands, this costs `B * log2(B) + 1` constraints, where `B = I::BITS`.
+    pub fn shr_barrel_checked(&self, rhs: &U32<E>) -> Self {
+        // Retrieve the index for the first upper bit from the RHS, which must be zero.
+        let first_upper_bit_index = I::BITS.trailing_zeros() as usize;
+
+        // Ensure that `rhs` is less than `I::BITS`.
+        Boolean::assert_bits_are_zero(&rhs.bits_le[first_upper_bit_index..]);
+
+        // Shift the bits of `self`, using the lower bits of `rhs`.
+        let bits_le = Self::barrel_shr(self.bits_le.clone(), &rhs.bits_le[..first_upper_bit_index], &self.shr_fill());
+        Self { bits_le, phantom: Default::default() }
+    }
+
+    /// Returns `self >> (rhs % I::BITS)`, in the same manner as `shr_wrapped`.
+    ///
+    /// This is implemented as a barrel shifter: for each of the lower `log2(I::BITS)` bits of `rhs`,
+    /// the intermediate result is conditionally shifted by the corresponding power of two, using `ternary`.
+    /// Signed integers are shifted arithmetically (filling with the sign bit), and unsigned integers logically.
+    /// The remaining bits of `rhs` are ignored, which reduces the shift amount modulo `I::BITS`.
+    ///
+    /// For non-constant operands, this costs `B * log2(B)` constraints, where `B = I::BITS`,
+    /// and is free if `rhs` is constant.
+    pub fn shr_barrel_wrapped(&self, rhs: &U32<E>) -> Self {
+        // Retrieve the index for the first upper bit from the RHS that we mask.
+        let first_upper_bit_index = I::BITS.trailing_zeros() as usize;
+
+        // Shift the bits of `self`, using the lower bits of `rhs`.
+        let bits_le = Self::barrel_shr(self.bits_le.clone(), &rhs.bits_le[..first_upper_bit_index], &self.shr_fill());
+        Self { bits_le, phantom: Default::default() }
+    }
+
+    /// Returns the bit that is shifted in by a right shift, which is the sign bit for signed integers,
+    /// and zero for unsigned integers.
+    fn shr_fill(&self) -> Boolean<E> {
+        match I::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        }
+    }
+
+    /// Shifts the given bits towards the least significant bit, by the amount given in `amount_bits_le`.
+    /// The vacated bits are filled with `fill`, and the bits shifted past the start are discarded.
+    fn barrel_shr(mut bits_le: Vec<Boolean<E>>, amount_bits_le: &[Boolean<E>], fill: &Boolean<E>) -> Vec<Boolean<E>> {
+        for (i, bit) in amount_bits_le.iter().enumerate() {
+            // Compute the bits shifted by `2^i`.
+            let distance = core::cmp::min(1 << i, bits_le.len());
+            let mut shifted_bits_le = bits_le[distance..].to_vec();
+            shifted_bits_le.resize(bits_le.len(), fill.clone());
+
+            // Select the shifted bits, if the bit of the shift amount is set.
+            bits_le = shifted_bits_le
+                .iter()
+                .zip_eq(bits_le.iter())
+                .map(|(shifted_bit, bit_le)| Boolean::ternary(bit, shifted_bit, bit_le))
+                .collect();
+        }
+        bits_le
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 64;
+
+    fn check_shr_barrel_checked<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, u32>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = U32::<Circuit>::new(mode_b, second);
+        match first.checked_shr(*second) {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.shr_barrel_checked(&b);
+                assert_eq!(expected, *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                match (mode_a.is_constant(), mode_b.is_constant()) {
+                    // A constant shift amount is a reindexing of the bits.
+                    (_, true) => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                    // Otherwise, each of the `log2(I::BITS)` layers costs `I::BITS` constraints,
+                    // and the range check on `rhs` costs one constraint.
+                    (false, false) => {
+                        assert_eq!(I::BITS * I::BITS.trailing_zeros() as u64 + 1, Circuit::num_constraints_in_scope())
+                    }
+                    (true, false) => (),
+                }
+            }),
+            None => match mode_b.is_constant() {
+                true => check_operation_halts(&a, &b, Integer::shr_barrel_checked),
+                false => Circuit::scope(name, || {
+                    let _candidate = a.shr_barrel_checked(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn check_shr_barrel_wrapped<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, u32>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = U32::<Circuit>::new(mode_b, second);
+        let expected = first.wrapping_shr(*second);
+        Circuit::scope(name, || {
+            let candidate = a.shr_barrel_wrapped(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            match (mode_a.is_constant(), mode_b.is_constant()) {
+                // A constant shift amount is a reindexing of the bits.
+                (_, true) => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                // Otherwise, each of the `log2(I::BITS)` layers costs `I::BITS` constraints.
+                (false, false) => {
+                    assert_eq!(I::BITS * I::BITS.trailing_zeros() as u64, Circuit::num_constraints_in_scope())
+                }
+                (true, false) => (),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            // Sample a shift amount that is mostly in range, and occasionally out of range.
+            let second = console::Integer::new(u32::rand(&mut rng) % (I::BITS as u32 + 2));
+
+            let name = format!("ShrBarrel: {mode_a} >> {mode_b} {i}");
+            check_shr_barrel_checked::<I>(&name, first, second, mode_a, mode_b);
+            check_shr_barrel_wrapped::<I>(&name, first, second, mode_a, mode_b);
+
+            // Shift by a random amount, which is almost always out of range.
+            let second = Uniform::rand(&mut rng);
+            let name = format!("ShrBarrel: {mode_a} >> {mode_b} (random amount) {i}");
+            check_shr_barrel_checked::<I>(&name, first, second, mode_a, mode_b);
+            check_shr_barrel_wrapped::<I>(&name, first, second, mode_a, mode_b);
+        }
+
+        // Check the boundary shift amounts, including the identity shift by zero.
+        for shift in [0, 1, I::BITS as u32 - 1, I::BITS as u32, u32::MAX] {
+            for value in
+                [console::Integer::zero(), console::Integer::one(), console::Integer::MAX, console::Integer::MIN]
+            {
+                let name = format!("ShrBarrel: {value} >> {shift}");
+                check_shr_barrel_checked::<I>(&name, value, console::Integer::new(shift), mode_a, mode_b);
+                check_shr_barrel_wrapped::<I>(&name, value, console::Integer::new(shift), mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, shr_barrel);
+    test_integer_binary!(run_test, i16, shr_barrel);
+    test_integer_binary!(run_test, i32, shr_barrel);
+    test_integer_binary!(run_test, i64, shr_barrel);
+    test_integer_binary!(run_test, i128, shr_barrel);
+
+    test_integer_binary!(run_test, u8, shr_barrel);
+    test_integer_binary!(run_test, u16, shr_barrel);
+    test_integer_binary!(run_test, u32, shr_barrel);
+    test_integer_binary!(run_test, u64, shr_barrel);
+    test_integer_binary!(run_test, u128, shr_barrel);
+}
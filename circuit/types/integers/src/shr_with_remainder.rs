@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the quotient and remainder of `self` divided by `2^k`, computed by shifting `self` right by `k` bits.
+    /// The quotient is `self >> k`, and the remainder is the integer formed by the `k` low bits that were shifted out.
+    ///
+    /// For signed integers, the quotient is rounded towards negative infinity, so the remainder is never negative.
+    pub fn shr_with_remainder(&self, k: u32) -> (Self, Self) {
+        // Ensure the shift amount is within the bitwidth.
+        if k as u64 >= I::BITS {
+            E::halt(format!("Shift amount ({k}) exceeds the allowed bitwidth ({})", I::BITS))
+        }
+
+        // Split the bits of `self` into the bits that are shifted out and the bits that are retained.
+        let (lower_bits_le, upper_bits_le) = self.bits_le.split_at(k as usize);
+
+        // Initialize the quotient from the upper bits, extended as in `shr_wrapped`.
+        let mut quotient_bits_le = upper_bits_le.to_vec();
+        match I::is_signed() {
+            // Sign-extend the quotient by `k`.
+            true => quotient_bits_le.resize(I::BITS as usize, self.msb().clone()),
+            // Zero-extend the quotient by `k`.
+            false => quotient_bits_le.resize(I::BITS as usize, Boolean::constant(false)),
+        }
+
+        // Initialize the remainder from the lower bits, zero-extended to the bitwidth.
+        // Note: The remainder is non-negative for signed integers, as `k` is less than `I::BITS`.
+        let mut remainder_bits_le = lower_bits_le.to_vec();
+        remainder_bits_le.resize(I::BITS as usize, Boolean::constant(false));
+
+        let quotient = Self { bits_le: quotient_bits_le, phantom: Default::default() };
+        let remainder = Self { bits_le: remainder_bits_le, phantom: Default::default() };
+        (quotient, remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 8;
+
+    fn check_shr_with_remainder<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        k: u32,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+
+        Circuit::scope(name, || {
+            let (quotient, remainder) = a.shr_with_remainder(k);
+            let (quotient, remainder) = (*quotient.eject_value(), *remainder.eject_value());
+
+            // Check the quotient against the console shift.
+            assert_eq!(*value >> k as usize, quotient);
+            // Check that the remainder is non-negative and less than `2^k`.
+            assert!(remainder >= I::zero());
+            assert_eq!(I::zero(), remainder >> k as usize);
+            // Check that `quotient * 2^k + remainder == self`.
+            // Note: The wrapping operations are exact, as `2^(BITS - 1)` wraps to `I::MIN` for signed integers.
+            assert_eq!(*value, quotient.wrapping_mul(&(I::one() << k as usize)).wrapping_add(&remainder));
+
+            // Check that the shift is free of constraints.
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for k in 0..I::BITS as u32 {
+            for i in 0..ITERATIONS {
+                let value = Uniform::rand(&mut rng);
+
+                let name = format!("ShrWithRemainder: {mode} >> {k} {i}");
+                check_shr_with_remainder::<I>(&name, value, k, mode);
+            }
+
+            // Check the zero and bounds cases.
+            for value in [console::Integer::zero(), console::Integer::MIN, console::Integer::MAX] {
+                let name = format!("ShrWithRemainder: {mode} {value} >> {k}");
+                check_shr_with_remainder::<I>(&name, value, k, mode);
+            }
+        }
+
+        // Check that shifting by the bitwidth halts.
+        let a = Integer::<Circuit, I>::new(mode, Uniform::rand(&mut rng));
+        check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.shr_with_remainder(I::BITS as u32));
+    }
+
+    test_integer_unary!(run_test, i8, shr_with_remainder);
+    test_integer_unary!(run_test, i16, shr_with_remainder);
+    test_integer_unary!(run_test, i32, shr_with_remainder);
+    test_integer_unary!(run_test, i64, shr_with_remainder);
+    test_integer_unary!(run_test, i128, shr_with_remainder);
+
+    test_integer_unary!(run_test, u8, shr_with_remainder);
+    test_integer_unary!(run_test, u16, shr_with_remainder);
+    test_integer_unary!(run_test, u32, shr_with_remainder);
+    test_integer_unary!(run_test, u64, shr_with_remainder);
+    test_integer_unary!(run_test, u128, shr_with_remainder);
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Signum for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `-1` if `self` is negative, `0` if `self` is zero, and `1` if `self` is positive.
+    /// Note that unsigned integers are never negative, and hence only ever return `0` or `1`.
+    fn signum(&self) -> Self::Output {
+        // Determine whether `self` is zero.
+        let is_nonzero = !self.is_equal(&Integer::zero());
+
+        // Rather than selecting between `-1`, `0`, and `1` via `ternary`, the output bits are constructed directly.
+        // The least significant bit is set if and only if `self` is nonzero, as both `-1` and `1` are odd,
+        // and the remaining bits are set if and only if `self` is negative.
+        let sign_bit = match I::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        };
+        let mut bits_le = vec![is_nonzero];
+        bits_le.extend(core::iter::repeat(sign_bit).take(I::BITS as usize - 1));
+
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Signum<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(I::BITS, 0, 0, 0),
+            _ => Count::is(I::BITS, 0, 2, 2),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Signum<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_signum<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = match *value {
+            value if value < I::zero() => I::zero() - I::one(),
+            value if value == I::zero() => I::zero(),
+            _ => I::one(),
+        };
+        Circuit::scope(name, || {
+            let candidate = a.signum();
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(Signum(Integer<I>) => Integer<I>, &mode);
+            assert_output_mode!(Signum(Integer<I>) => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("Signum: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_signum::<I>(&name, value, mode);
+        }
+
+        check_signum::<I>(&format!("Signum: {mode} zero"), console::Integer::zero(), mode);
+        check_signum::<I>(&format!("Signum: {mode} one"), console::Integer::one(), mode);
+        check_signum::<I>(&format!("Signum: {mode} max"), console::Integer::MAX, mode);
+        check_signum::<I>(&format!("Signum: {mode} min"), console::Integer::MIN, mode);
+
+        if I::is_signed() {
+            check_signum::<I>(&format!("Signum: {mode} negative one"), -console::Integer::one(), mode);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("Signum: {mode}");
+            check_signum::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, signum);
+    test_integer_unary!(run_test, i16, signum);
+    test_integer_unary!(run_test, i32, signum);
+    test_integer_unary!(run_test, i64, signum);
+    test_integer_unary!(run_test, i128, signum);
+
+    test_integer_unary!(run_test, u8, signum);
+    test_integer_unary!(run_test, u16, signum);
+    test_integer_unary!(run_test, u32, signum);
+    test_integer_unary!(run_test, u64, signum);
+    test_integer_unary!(run_test, u128, signum);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, signum, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, signum, exhaustive);
+}
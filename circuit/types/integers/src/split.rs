@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the lower and upper halves of `self`, as integers of type `T` with half the bit width of `I`.
+    ///
+    /// This is a reslicing of the bits, and requires no constraints.
+    /// Halts if the bit width of `T` is not half the bit width of `I`.
+    pub fn split<T: IntegerType>(&self) -> (Integer<E, T>, Integer<E, T>) {
+        // Ensure the output type is half the bit width of `self`.
+        if 2 * T::BITS != I::BITS {
+            E::halt(format!("Cannot split a {}-bit integer into two {}-bit integers", I::BITS, T::BITS))
+        }
+        let half = T::BITS as usize;
+        (self.extract_bits(0, half), self.extract_bits(half, half))
+    }
+
+    /// Returns the upper and lower parts of `self` at bit `k`, i.e. `(self >> k, self & (2^k - 1))`,
+    /// where both parts are zero-extended to integers of type `I`.
+    ///
+    /// This is a reslicing of the bits, and requires no constraints.
+    /// Note that the upper part is a logical shift, so the sign bit of a signed integer is not replicated.
+    /// Halts if `k` exceeds the bit width of `I`.
+    pub fn split_at_bit(&self, k: usize) -> (Self, Self) {
+        if k > I::BITS as usize {
+            E::halt(format!("Attempted to split a {}-bit integer at bit {k}", I::BITS))
+        }
+        let upper_bits_le = self.bits_le[k..].iter().cloned().chain(core::iter::repeat(Boolean::constant(false)));
+        let upper = Self { bits_le: upper_bits_le.take(I::BITS as usize).collect(), phantom: Default::default() };
+        (upper, self.truncate_bits(k))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_split<I: IntegerType, T: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Compute the expected halves by masking and shifting.
+        let value = value.to_u128().unwrap();
+        let mask = u128::MAX >> (128 - T::BITS);
+        let (expected_lo, expected_hi) = (value & mask, (value >> T::BITS) & mask);
+        Circuit::scope(name, || {
+            let (lo, hi) = a.split::<T>();
+            assert_eq!(expected_lo, lo.eject_value().to_u128().unwrap());
+            assert_eq!(expected_hi, hi.eject_value().to_u128().unwrap());
+            assert_eq!((mode, mode), (lo.eject_mode(), hi.eject_mode()));
+            // Ensure the halves concatenate back to the original value.
+            assert_eq!(a.eject_value(), Integer::<Circuit, I>::concat(&lo, &hi).eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, T: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+
+            let name = format!("Split: {mode} {i}");
+            check_split::<I, T>(&name, value, mode);
+        }
+
+        check_split::<I, T>("0", console::Integer::zero(), mode);
+        check_split::<I, T>("1", console::Integer::one(), mode);
+        check_split::<I, T>("MAX", console::Integer::MAX, mode);
+
+        // Ensure a mismatched bit width halts.
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::zero());
+        check_unary_operation_halts(&a, |a| a.split::<I>());
+        Circuit::reset();
+    }
+
+    fn check_split_at_bit<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        k: usize,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Compute the expected parts by shifting and masking the unsigned bit pattern.
+        let bits = value.to_bits_le().iter().rev().fold(0u128, |acc, bit| (acc << 1) | *bit as u128);
+        let expected_upper = bits.checked_shr(k as u32).unwrap_or(0);
+        let expected_lower = bits & 1u128.checked_shl(k as u32).unwrap_or(0).wrapping_sub(1);
+        Circuit::scope(name, || {
+            let (upper, lower) = a.split_at_bit(k);
+            let eject = |integer: &Integer<Circuit, I>| {
+                integer.eject_value().to_bits_le().iter().rev().fold(0u128, |acc, bit| (acc << 1) | *bit as u128)
+            };
+            assert_eq!(expected_upper, eject(&upper));
+            assert_eq!(expected_lower, eject(&lower));
+            // Ensure the parts recombine to the original value.
+            assert_eq!(bits, eject(&upper).checked_shl(k as u32).unwrap_or(0) | eject(&lower));
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_split_at_bit_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS / 8 {
+            let value = Uniform::rand(&mut rng);
+            for k in 0..=I::BITS as usize {
+                let name = format!("SplitAtBit: {mode} {k} {i}");
+                check_split_at_bit::<I>(&name, value, k, mode);
+            }
+        }
+
+        for k in 0..=I::BITS as usize {
+            check_split_at_bit::<I>(&format!("SplitAtBit: {mode} {k} MAX"), console::Integer::MAX, k, mode);
+            check_split_at_bit::<I>(&format!("SplitAtBit: {mode} {k} MIN"), console::Integer::MIN, k, mode);
+        }
+
+        // Ensure a split point beyond the bit width halts.
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::zero());
+        check_unary_operation_halts(&a, |a| a.split_at_bit(I::BITS as usize + 1));
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_split_at_bit() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            run_split_at_bit_test::<u8>(mode);
+            run_split_at_bit_test::<i8>(mode);
+            run_split_at_bit_test::<u16>(mode);
+            run_split_at_bit_test::<i32>(mode);
+            run_split_at_bit_test::<u64>(mode);
+            run_split_at_bit_test::<i128>(mode);
+            run_split_at_bit_test::<u128>(mode);
+        }
+    }
+
+    #[test]
+    fn test_split_u16() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            run_test::<u16, u8>(mode);
+        }
+    }
+
+    #[test]
+    fn test_split_u32() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            run_test::<u32, u16>(mode);
+        }
+    }
+
+    #[test]
+    fn test_split_u64() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            run_test::<u64, u32>(mode);
+        }
+    }
+
+    #[test]
+    fn test_split_u128() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            run_test::<u128, u64>(mode);
+        }
+    }
+}
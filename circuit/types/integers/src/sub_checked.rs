@@ -213,6 +213,33 @@ mod tests {
         check_sub::<I>("MIN - 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
     }
 
+    fn run_operator_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let forms: [BinaryOperator<I>; 6] = binary_operator_forms!(-, -=);
+        let check = |candidate: &Integer<Circuit, I>| {
+            assert_count!(Sub(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Sub(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        };
+
+        let mut samples: Vec<(console::Integer<_, I>, console::Integer<_, I>)> =
+            (0..ITERATIONS).map(|_| (Uniform::rand(&mut rng), Uniform::rand(&mut rng))).collect();
+
+        // Include operands that overflow and underflow, which the operator must reject.
+        samples.push((console::Integer::MIN, console::Integer::one()));
+        if I::is_signed() {
+            samples.push((console::Integer::MAX, -console::Integer::one()));
+        }
+
+        for (i, (first, second)) in samples.into_iter().enumerate() {
+            let a = Integer::<Circuit, I>::new(mode_a, first);
+            let b = Integer::<Circuit, I>::new(mode_b, second);
+            let expected = first.checked_sub(&second).map(console::Integer::new);
+            let name = format!("SubOperator: {mode_a} - {mode_b} {i}");
+            check_binary_operator_forms(&name, &forms, &a, &b, expected, check);
+        }
+    }
+
     fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
     where
         RangeInclusive<I>: Iterator<Item = I>,
@@ -240,6 +267,11 @@ mod tests {
     test_integer_binary!(run_test, u64, minus);
     test_integer_binary!(run_test, u128, minus);
 
+    test_integer_binary!(run_operator_test, i8, minus_operator);
+    test_integer_binary!(run_operator_test, i64, minus_operator);
+    test_integer_binary!(run_operator_test, u32, minus_operator);
+    test_integer_binary!(run_operator_test, u128, minus_operator);
+
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, minus, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, minus, exhaustive);
 }
@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> SubSaturating<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn sub_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the difference and return the new constant.
+            witness!(|self, other| console::Integer::new(self.saturating_sub(*other)))
+        } else {
+            // Compute the wrapped difference of `self` and `other`, along with an overflow flag.
+            let (difference, is_overflow) = self.overflowing_sub(other);
+
+            // Determine the saturation bound.
+            let bound = match I::is_signed() {
+                // For signed subtraction, the bound is `I::MIN` if `self` is negative, and `I::MAX` otherwise.
+                // Note: The bound is constructed directly from the sign of `self`, as in `add_saturating`.
+                true => {
+                    let mut bound_bits_le = vec![!self.msb(); I::BITS as usize - 1];
+                    bound_bits_le.push(self.msb().clone());
+                    Integer { bits_le: bound_bits_le, phantom: Default::default() }
+                }
+                // For unsigned subtraction, the bound is zero.
+                false => Integer::zero(),
+            };
+
+            Self::ternary(&is_overflow, &bound, &difference)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn SubSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match I::is_signed() {
+            true => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (Mode::Constant, _) => Count::is(0, 0, 2 * I::BITS + 3, 2 * I::BITS + 4),
+                (_, Mode::Constant) => Count::is(0, 0, 2 * I::BITS + 2, 2 * I::BITS + 3),
+                (_, _) => Count::is(0, 0, 2 * I::BITS + 4, 2 * I::BITS + 5),
+            },
+            false => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (_, _) => Count::is(I::BITS, 0, 2 * I::BITS + 1, 2 * I::BITS + 2),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn SubSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_sub<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::new(mode_b, second);
+        let expected = (*first).saturating_sub(*second);
+        Circuit::scope(name, || {
+            let candidate = a.sub_saturating(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(SubSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(SubSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("SubSaturating: {mode_a} - {mode_b} {i}");
+            check_sub::<I>(&name, first, second, mode_a, mode_b);
+            check_sub::<I>(&name, second, first, mode_a, mode_b); // Swap the operands.
+
+            // Check that subtracting from zero saturates for unsigned integers.
+            let name = format!("SubSaturating: 0 - {mode_b} {i}");
+            check_sub::<I>(&name, console::Integer::zero(), second, mode_a, mode_b);
+        }
+
+        // Underflow
+        check_sub::<I>("MIN - 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_sub::<I>("MIN - MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+
+        // Overflow
+        if I::is_signed() {
+            check_sub::<I>("MAX - (-1)", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+            check_sub::<I>("MAX - MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+            check_sub::<I>("0 - MIN", console::Integer::zero(), console::Integer::MIN, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("SubSaturating: ({first} - {second})");
+                check_sub::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, minus);
+    test_integer_binary!(run_test, i16, minus);
+    test_integer_binary!(run_test, i32, minus);
+    test_integer_binary!(run_test, i64, minus);
+    test_integer_binary!(run_test, i128, minus);
+
+    test_integer_binary!(run_test, u8, minus);
+    test_integer_binary!(run_test, u16, minus);
+    test_integer_binary!(run_test, u32, minus);
+    test_integer_binary!(run_test, u64, minus);
+    test_integer_binary!(run_test, u128, minus);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, minus, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, minus, exhaustive);
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> TrailingOnes for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of trailing ones in the binary representation of `self`.
+    fn trailing_ones(&self) -> Self::Output {
+        // The trailing ones of `self` are the trailing zeros of its complement.
+        // Note: If every bit of `self` is set, then the result is `I::BITS`.
+        (!self).trailing_zeros()
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn TrailingOnes<Output = U32<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(32, 0, 0, 0),
+            _ => Count::is(0, 0, I::BITS + 31, I::BITS + 32),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn TrailingOnes<Output = U32<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_trailing_ones<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = (*value).trailing_ones();
+        Circuit::scope(name, || {
+            let candidate = a.trailing_ones();
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(TrailingOnes(Integer<I>) => Integer<u32>, &mode);
+            assert_output_mode!(TrailingOnes(Integer<I>) => Integer<u32>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("TrailingOnes: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_trailing_ones::<I>(&name, value, mode);
+        }
+
+        check_trailing_ones::<I>(&format!("TrailingOnes: {mode} zero"), console::Integer::zero(), mode);
+        check_trailing_ones::<I>(&format!("TrailingOnes: {mode} one"), console::Integer::one(), mode);
+        check_trailing_ones::<I>(&format!("TrailingOnes: {mode} max"), console::Integer::MAX, mode);
+        check_trailing_ones::<I>(&format!("TrailingOnes: {mode} min"), console::Integer::MIN, mode);
+        check_trailing_ones::<I>(&format!("TrailingOnes: {mode} all ones"), console::Integer::new(!I::zero()), mode);
+
+        // Check that each mask of the form `2^i - 1` returns its exponent.
+        for i in 0..I::BITS as usize {
+            let name = format!("TrailingOnes: {mode} 2^{i} - 1");
+            check_trailing_ones::<I>(&name, console::Integer::new((I::one() << i).wrapping_sub(&I::one())), mode);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("TrailingOnes: {mode}");
+            check_trailing_ones::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, trailing_ones);
+    test_integer_unary!(run_test, i16, trailing_ones);
+    test_integer_unary!(run_test, i32, trailing_ones);
+    test_integer_unary!(run_test, i64, trailing_ones);
+    test_integer_unary!(run_test, i128, trailing_ones);
+
+    test_integer_unary!(run_test, u8, trailing_ones);
+    test_integer_unary!(run_test, u16, trailing_ones);
+    test_integer_unary!(run_test, u32, trailing_ones);
+    test_integer_unary!(run_test, u64, trailing_ones);
+    test_integer_unary!(run_test, u128, trailing_ones);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, trailing_ones, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, trailing_ones, exhaustive);
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self` with all but the lowest `k` bits set to zero, i.e. `self mod 2^k` for unsigned integers.
+    ///
+    /// This is a reslice of the bits of `self`, and requires no constraints.
+    /// If `k == I::BITS`, this is the identity, and if `k == 0`, the result is zero.
+    pub fn truncate_bits(&self, k: usize) -> Self {
+        if k > I::BITS as usize {
+            E::halt(format!("Attempted to truncate a {}-bit integer to {k} bits", I::BITS))
+        }
+        let bits_le = self.bits_le[..k].iter().cloned().chain(core::iter::repeat(Boolean::constant(false)));
+        Self { bits_le: bits_le.take(I::BITS as usize).collect(), phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_truncate_bits<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        k: usize,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let mask = match k == I::BITS as usize {
+            true => !I::zero(),
+            false => (I::one() << k) - I::one(),
+        };
+        let expected = console::Integer::new(*value & mask);
+        Circuit::scope(name, || {
+            let candidate = a.truncate_bits(k);
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            for k in 0..=I::BITS as usize {
+                let name = format!("TruncateBits: {mode} {i} {k}");
+                check_truncate_bits::<I>(&name, value, k, mode);
+            }
+        }
+
+        for k in 0..=I::BITS as usize {
+            check_truncate_bits::<I>(&format!("TruncateBits: {mode} zero {k}"), console::Integer::zero(), k, mode);
+            check_truncate_bits::<I>(&format!("TruncateBits: {mode} max {k}"), console::Integer::MAX, k, mode);
+            check_truncate_bits::<I>(&format!("TruncateBits: {mode} min {k}"), console::Integer::MIN, k, mode);
+        }
+    }
+
+    #[test]
+    fn test_truncate_bits_beyond_width_halts() {
+        let a = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::one());
+        let result = std::panic::catch_unwind(|| a.truncate_bits(9));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, truncate_bits);
+    test_integer_unary!(run_test, i16, truncate_bits);
+    test_integer_unary!(run_test, i32, truncate_bits);
+    test_integer_unary!(run_test, i64, truncate_bits);
+    test_integer_unary!(run_test, i128, truncate_bits);
+
+    test_integer_unary!(run_test, u8, truncate_bits);
+    test_integer_unary!(run_test, u16, truncate_bits);
+    test_integer_unary!(run_test, u32, truncate_bits);
+    test_integer_unary!(run_test, u64, truncate_bits);
+    test_integer_unary!(run_test, u128, truncate_bits);
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self ^ exponent` computed outside the circuit, or `None` if the result overflows
+    /// or the exponent is negative. This is useful for deciding the shape of a circuit from constants,
+    /// and unlike `pow_checked`, it does not add anything to the circuit.
+    ///
+    /// This halts if either operand is not a constant.
+    pub fn try_pow(&self, exponent: &Integer<E, I>) -> Option<I> {
+        if !self.is_constant() || !exponent.is_constant() {
+            E::halt("Attempted to compute `try_pow` on a non-constant operand")
+        }
+
+        let base = *self.eject_value();
+        let exponent_value = *exponent.eject_value();
+        let exponent = match exponent_value.to_u32() {
+            Some(exponent) => exponent,
+            None if exponent_value < I::zero() => return None,
+            // Beyond `u32::MAX`, the result only depends on the parity of the exponent,
+            // as any base with a magnitude of at least 2 has already overflowed.
+            None => match exponent_value % (I::one() + I::one()) == I::zero() {
+                true => u32::MAX - 1,
+                false => u32::MAX,
+            },
+        };
+        base.checked_pow(&exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    /// Returns the given value as a primitive of type `I`.
+    fn primitive<I: IntegerType>(value: u32) -> I {
+        I::from_str(&value.to_string()).unwrap()
+    }
+
+    fn check_try_pow<I: IntegerType>(base: I, exponent: I, expected: Option<I>) {
+        let a = Integer::<Circuit, I>::constant(console::Integer::new(base));
+        let b = Integer::<Circuit, I>::constant(console::Integer::new(exponent));
+        Circuit::scope(format!("TryPow: {base} {exponent}"), || {
+            assert_eq!(expected, a.try_pow(&b));
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let base: I = Uniform::rand(&mut rng);
+            let exponent = u32::rand(&mut rng) % (2 * I::BITS as u32);
+            let expected = base.checked_pow(&exponent);
+            check_try_pow(base, primitive::<I>(exponent), expected);
+        }
+
+        let two = I::one() + I::one();
+        let bits = primitive::<I>(I::BITS as u32);
+
+        // Check the boundary between non-overflowing and overflowing exponents.
+        check_try_pow(two, bits - two, Some(I::one().wrapping_shl(I::BITS as u32 - 2)));
+        check_try_pow(two, bits, None);
+        check_try_pow(I::zero(), I::zero(), Some(I::one()));
+
+        // Check that the largest exponents only overflow for bases with a magnitude of at least 2.
+        check_try_pow(I::zero(), I::MAX, Some(I::zero()));
+        check_try_pow(I::one(), I::MAX, Some(I::one()));
+        check_try_pow(two, I::MAX, None);
+
+        // Check that negative exponents are rejected.
+        if I::is_signed() {
+            check_try_pow(I::one(), I::zero() - I::one(), None);
+            check_try_pow(two, I::MIN, None);
+        }
+    }
+
+    fn assert_try_pow_halts<I: IntegerType + RefUnwindSafe>(base_mode: Mode, exponent_mode: Mode) {
+        let a = Integer::<Circuit, I>::new(base_mode, console::Integer::one());
+        let b = Integer::<Circuit, I>::new(exponent_mode, console::Integer::one());
+        check_operation_halts(&a, &b, Integer::try_pow);
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_try_pow_halts_on_non_constant() {
+        for (base_mode, exponent_mode) in [
+            (Mode::Constant, Mode::Public),
+            (Mode::Constant, Mode::Private),
+            (Mode::Public, Mode::Constant),
+            (Mode::Private, Mode::Constant),
+            (Mode::Private, Mode::Private),
+        ] {
+            assert_try_pow_halts::<u8>(base_mode, exponent_mode);
+            assert_try_pow_halts::<i64>(base_mode, exponent_mode);
+        }
+    }
+
+    #[test]
+    fn test_i8_try_pow() {
+        run_test::<i8>();
+    }
+
+    #[test]
+    fn test_i16_try_pow() {
+        run_test::<i16>();
+    }
+
+    #[test]
+    fn test_i32_try_pow() {
+        run_test::<i32>();
+    }
+
+    #[test]
+    fn test_i64_try_pow() {
+        run_test::<i64>();
+    }
+
+    #[test]
+    fn test_i128_try_pow() {
+        run_test::<i128>();
+    }
+
+    #[test]
+    fn test_u8_try_pow() {
+        run_test::<u8>();
+    }
+
+    #[test]
+    fn test_u16_try_pow() {
+        run_test::<u16>();
+    }
+
+    #[test]
+    fn test_u32_try_pow() {
+        run_test::<u32>();
+    }
+
+    #[test]
+    fn test_u64_try_pow() {
+        run_test::<u64>();
+    }
+
+    #[test]
+    fn test_u128_try_pow() {
+        run_test::<u128>();
+    }
+}
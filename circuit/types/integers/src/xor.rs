@@ -105,7 +105,9 @@ mod tests {
     use super::*;
     use snarkvm_circuit_environment::Circuit;
 
-    use core::ops::RangeInclusive;
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
 
     const ITERATIONS: u64 = 128;
 
@@ -157,6 +159,27 @@ mod tests {
         check_bitxor::<I>("MIN ^ 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
     }
 
+    fn run_operator_test<I: IntegerType + BitXor<Output = I> + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let forms: [BinaryOperator<I>; 6] = binary_operator_forms!(^, ^=);
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let a = Integer::<Circuit, I>::new(mode_a, first);
+            let b = Integer::new(mode_b, second);
+            let check = |candidate: &Integer<Circuit, I>| {
+                assert_count!(BitXor(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                assert_output_mode!(BitXor(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)), candidate);
+            };
+
+            let name = format!("BitXorOperator: ({mode_a} ^ {mode_b}) {i}");
+            check_binary_operator_forms(&name, &forms, &a, &b, Some(first ^ second), check);
+        }
+    }
+
     fn run_exhaustive_test<I: IntegerType + BitXor<Output = I>>(mode_a: Mode, mode_b: Mode)
     where
         RangeInclusive<I>: Iterator<Item = I>,
@@ -184,6 +207,9 @@ mod tests {
     test_integer_binary!(run_test, u64, bitxor);
     test_integer_binary!(run_test, u128, bitxor);
 
+    test_integer_binary!(run_operator_test, i16, bitxor_operator);
+    test_integer_binary!(run_operator_test, u64, bitxor_operator);
+
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, bitxor, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, bitxor, exhaustive);
 }
@@ -21,6 +21,13 @@ pub trait AddChecked<Rhs: ?Sized = Self> {
     fn add_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for adding two values modulo a given modulus.
+pub trait AddMod<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn add_mod(&self, rhs: &Rhs, modulus: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for adding two values, bounding the sum to `MAX` if an overflow occurs.
 pub trait AddSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -35,6 +42,30 @@ pub trait AddWrapped<Rhs: ?Sized = Self> {
     fn add_wrapped(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for adding two values, returning the wrapped sum and whether an overflow occurred.
+pub trait OverflowingAdd<Rhs: ?Sized = Self> {
+    type Boolean;
+    type Output;
+
+    fn overflowing_add(&self, rhs: &Rhs) -> (Self::Output, Self::Boolean);
+}
+
+/// Binary operator for multiplying two values, returning the wrapped product and whether an overflow occurred.
+pub trait OverflowingMul<Rhs: ?Sized = Self> {
+    type Boolean;
+    type Output;
+
+    fn overflowing_mul(&self, rhs: &Rhs) -> (Self::Output, Self::Boolean);
+}
+
+/// Binary operator for subtracting two values, returning the wrapped difference and whether an overflow occurred.
+pub trait OverflowingSub<Rhs: ?Sized = Self> {
+    type Boolean;
+    type Output;
+
+    fn overflowing_sub(&self, rhs: &Rhs) -> (Self::Output, Self::Boolean);
+}
+
 /// Binary operator for dividing two values, without checking specific conditions.
 pub trait DivUnchecked<Rhs: ?Sized = Self> {
     type Output;
@@ -49,6 +80,20 @@ pub trait DivChecked<Rhs: ?Sized = Self> {
     fn div_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for dividing two values, rounding the quotient towards positive infinity.
+pub trait DivCeil<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn div_ceil(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for dividing two values, rounding the quotient such that the remainder is non-negative.
+pub trait DivEuclidean<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn div_euclidean(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for dividing two values, bounding the quotient to `MAX` or `MIN` if an overflow occurs.
 pub trait DivSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -63,6 +108,20 @@ pub trait DivWrapped<Rhs: ?Sized = Self> {
     fn div_wrapped(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for computing the greatest common divisor of two values.
+pub trait Gcd<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn gcd(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for computing the midpoint of two values, rounding towards negative infinity.
+pub trait Midpoint<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn midpoint(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for modding two values.
 pub trait Modulo<Rhs: ?Sized = Self> {
     type Output;
@@ -77,6 +136,13 @@ pub trait MulChecked<Rhs: ?Sized = Self> {
     fn mul_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for multiplying two values modulo a given modulus.
+pub trait MulMod<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn mul_mod(&self, rhs: &Rhs, modulus: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for multiplying two values, bounding the product to `MAX` if an overflow occurs.
 pub trait MulSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -98,6 +164,20 @@ pub trait PowChecked<Rhs: ?Sized = Self> {
     fn pow_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for exponentiating two values modulo a given modulus.
+pub trait PowMod<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn pow_mod(&self, rhs: &Rhs, modulus: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for exponentiating two values, bounding the result to `MAX` or `MIN` if an overflow occurs.
+pub trait PowSaturating<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn pow_saturating(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for exponentiating two values, wrapping the result if an overflow occurs.
 pub trait PowWrapped<Rhs: ?Sized = Self> {
     type Output;
@@ -112,6 +192,13 @@ pub trait RemChecked<Rhs: ?Sized = Self> {
     fn rem_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for dividing two values and returning the non-negative remainder.
+pub trait RemEuclidean<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rem_euclidean(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for dividing two values, bounding the remainder to `MAX` or `MIN` if an overflow occurs.
 pub trait RemSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -198,6 +285,34 @@ pub trait AbsWrapped {
     fn abs_wrapped(self) -> Self::Output;
 }
 
+/// Binary operator for retrieving the floor of the logarithm of the value, with respect to the given base.
+pub trait Ilog<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn ilog(&self, base: &Rhs) -> Self::Output;
+}
+
+/// Unary operator for retrieving the floor of the base-2 logarithm of the value.
+pub trait Ilog2 {
+    type Output;
+
+    fn ilog2(&self) -> Self::Output;
+}
+
+/// Unary operator for retrieving the floor of the square root of the value.
+pub trait Isqrt {
+    type Output;
+
+    fn isqrt(&self) -> Self::Output;
+}
+
+/// Unary operator for retrieving the sign of the value, as `-1`, `0`, or `1`.
+pub trait Signum {
+    type Output;
+
+    fn signum(&self) -> Self::Output;
+}
+
 /// Unary operator for retrieving the doubled value.
 pub trait Double {
     type Output;
@@ -225,3 +340,18 @@ pub trait SquareRoot {
 
     fn square_root(&self) -> Result<Self::Output>;
 }
+
+/// Unary operator for casting a value into another type, enforcing the value is representable in the other type.
+pub trait CastChecked<T: Sized = Self> {
+    fn cast_checked(&self) -> T;
+}
+
+/// Unary operator for casting a value into another type, bounding the value to `MAX` or `MIN` if it is out of range.
+pub trait CastSaturating<T: Sized = Self> {
+    fn cast_saturating(&self) -> T;
+}
+
+/// Unary operator for casting a value into another type, truncating or extending the value as necessary.
+pub trait CastWrapped<T: Sized = Self> {
+    fn cast_wrapped(&self) -> T;
+}
@@ -40,6 +40,134 @@ pub trait Compare<Rhs: ?Sized = Self> {
     fn is_greater_than_or_equal(&self, other: &Rhs) -> Self::Output;
 }
 
+/// Operator for restricting a value to an inclusive range.
+pub trait Clamp<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` restricted to the range `[lower, upper]`.
+    fn clamp(&self, lower: &Rhs, upper: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for selecting the larger of two values.
+pub trait Max<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the larger of `self` and `other`.
+    fn max(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for selecting the smaller of two values.
+pub trait Min<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the smaller of `self` and `other`.
+    fn min(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Unary operator for counting the number of set bits.
+pub trait CountOnes {
+    type Output;
+
+    /// Returns the number of ones in the binary representation of `self`.
+    fn count_ones(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of unset bits.
+pub trait CountZeros {
+    type Output;
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    fn count_zeros(&self) -> Self::Output;
+}
+
+/// Binary operator for counting the number of bits that differ between two values.
+pub trait HammingDistance<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the number of bit positions at which `self` and `other` differ.
+    fn hamming_distance(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Unary operator for determining if the value is a power of two.
+pub trait IsPowerOfTwo {
+    type Output;
+
+    /// Returns `true` if `self` is a power of two.
+    fn is_power_of_two(&self) -> Self::Output;
+}
+
+/// Unary operator for computing the XOR of all bits.
+pub trait Parity {
+    type Output;
+
+    /// Returns `true` if the binary representation of `self` has an odd number of ones.
+    fn parity(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of leading zeros.
+pub trait LeadingZeros {
+    type Output;
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    fn leading_zeros(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of trailing zeros.
+pub trait TrailingZeros {
+    type Output;
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    fn trailing_zeros(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of leading ones.
+pub trait LeadingOnes {
+    type Output;
+
+    /// Returns the number of leading ones in the binary representation of `self`.
+    fn leading_ones(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of trailing ones.
+pub trait TrailingOnes {
+    type Output;
+
+    /// Returns the number of trailing ones in the binary representation of `self`.
+    fn trailing_ones(&self) -> Self::Output;
+}
+
+/// Unary operator for reversing the order of the bits.
+pub trait ReverseBits {
+    type Output;
+
+    /// Returns `self` with the order of its bits reversed.
+    fn reverse_bits(&self) -> Self::Output;
+}
+
+/// Unary operator for rotating the bits to the left by a constant amount, wrapping the truncated bits to the end.
+pub trait RotateLeft {
+    type Output;
+
+    /// Returns `self` with its bits rotated to the left by `n` (modulo the bitwidth).
+    fn rotate_left(&self, n: u32) -> Self::Output;
+}
+
+/// Unary operator for rotating the bits to the right by a constant amount, wrapping the truncated bits to the beginning.
+pub trait RotateRight {
+    type Output;
+
+    /// Returns `self` with its bits rotated to the right by `n` (modulo the bitwidth).
+    fn rotate_right(&self, n: u32) -> Self::Output;
+}
+
+/// Unary operator for reversing the order of the bytes.
+pub trait SwapBytes {
+    type Output;
+
+    /// Returns `self` with the order of its bytes reversed.
+    fn swap_bytes(&self) -> Self::Output;
+}
+
 /// Binary operator for performing `NOT (a AND b)`.
 pub trait Nand<Rhs: ?Sized = Self> {
     type Output;
@@ -56,6 +184,35 @@ pub trait Nor<Rhs: ?Sized = Self> {
     fn nor(&self, other: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for performing `NOT (a XOR b)`.
+pub trait Xnor<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `NOT (a XOR b)`.
+    fn xnor(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for carry-less multiplication, i.e. the product of two values as polynomials over GF(2).
+pub trait Clmul<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the lower half of the carry-less product of `self` and `other`.
+    fn clmul(&self, other: &Rhs) -> Self::Output;
+
+    /// Returns the lower and upper halves of the carry-less product of `self` and `other`.
+    fn clmul_wide(&self, other: &Rhs) -> (Self::Output, Self::Output);
+}
+
+/// Trait for selecting each bit from `first` where the corresponding bit of `mask` is set, and from `second` otherwise.
+pub trait SelectBits {
+    type Output;
+
+    /// Returns `(first & mask) | (second & !mask)`.
+    fn select_bits(mask: &Self, first: &Self, second: &Self) -> Self::Output
+    where
+        Self: Sized;
+}
+
 /// Trait for ternary operations.
 pub trait Ternary {
     type Boolean;
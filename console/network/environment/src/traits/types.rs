@@ -316,6 +316,7 @@ pub mod integer_type {
         CheckedNeg,
         CheckedRem,
         CheckedShr,
+        Num,
         One as NumOne,
         PrimInt,
         ToPrimitive,
@@ -345,6 +346,7 @@ pub mod integer_type {
         + FromStr<Err = ParseIntError>
         + Hash
         + Modulo
+        + Num<FromStrRadixErr = ParseIntError>
         + NumZero
         + NumOne
         + PartialOrd
@@ -20,12 +20,30 @@ impl<E: Environment, I: IntegerType> Parser for Integer<E, I> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.unwrap_or_default().to_string())(string)?;
-        // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+        // Parse the radix prefix and the digits from the string.
+        let (string, (radix, primitive)) = alt((
+            map(
+                pair(
+                    alt((tag("0x"), tag("0X"))),
+                    recognize(many1(terminated(one_of("0123456789abcdefABCDEF"), many0(char('_'))))),
+                ),
+                |(_, digits)| (16, digits),
+            ),
+            map(
+                pair(alt((tag("0o"), tag("0O"))), recognize(many1(terminated(one_of("01234567"), many0(char('_')))))),
+                |(_, digits)| (8, digits),
+            ),
+            map(
+                pair(alt((tag("0b"), tag("0B"))), recognize(many1(terminated(one_of("01"), many0(char('_')))))),
+                |(_, digits)| (2, digits),
+            ),
+            map(recognize(many1(terminated(one_of("0123456789"), many0(char('_'))))), |digits| (10, digits)),
+        ))(string)?;
         // Combine the sign and primitive.
         let primitive = negation + primitive;
-        // Parse the value from the string.
-        let (string, value) = map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
+        // Parse the value from the string, ensuring it is within the range of the type.
+        let (string, value) =
+            map_res(tag(Self::type_name()), |_| I::from_str_radix(&primitive.replace('_', ""), radix))(string)?;
 
         Ok((string, Integer::new(value)))
     }
@@ -90,6 +108,137 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_hex() -> Result<()> {
+        /// Formats the given value as a hexadecimal literal, and checks that it parses back to the value.
+        fn check_parse_hex<E: Environment, I: IntegerType>(rng: &mut TestRng) {
+            for _ in 0..ITERATIONS {
+                // Sample a random value.
+                let value: I = Uniform::rand(rng);
+
+                // Format the value with a sign and the magnitude in hexadecimal.
+                let (sign, magnitude) = match I::is_signed() {
+                    true => {
+                        let value = value.to_i128().unwrap();
+                        (if value < 0 { "-" } else { "" }, value.unsigned_abs())
+                    }
+                    false => ("", value.to_u128().unwrap()),
+                };
+                let type_name = Integer::<E, I>::type_name();
+
+                for literal in [
+                    format!("{sign}0x{magnitude:x}{type_name}"),
+                    format!("{sign}0X{magnitude:X}{type_name}"),
+                    format!("{sign}0x{magnitude:08x}{type_name}"),
+                ] {
+                    let (remainder, candidate) = Integer::<E, I>::parse(&literal).unwrap();
+                    assert_eq!(value, *candidate, "{literal}");
+                    assert_eq!("", remainder);
+                }
+            }
+        }
+
+        let mut rng = TestRng::default();
+
+        check_parse_hex::<CurrentEnvironment, u8>(&mut rng);
+        check_parse_hex::<CurrentEnvironment, u16>(&mut rng);
+        check_parse_hex::<CurrentEnvironment, u32>(&mut rng);
+        check_parse_hex::<CurrentEnvironment, u64>(&mut rng);
+        check_parse_hex::<CurrentEnvironment, u128>(&mut rng);
+
+        check_parse_hex::<CurrentEnvironment, i8>(&mut rng);
+        check_parse_hex::<CurrentEnvironment, i16>(&mut rng);
+        check_parse_hex::<CurrentEnvironment, i32>(&mut rng);
+        check_parse_hex::<CurrentEnvironment, i64>(&mut rng);
+        check_parse_hex::<CurrentEnvironment, i128>(&mut rng);
+
+        // Check underscores and corner cases.
+        assert_eq!(255, *Integer::<CurrentEnvironment, u8>::from_str("0xFFu8")?);
+        assert_eq!(255, *Integer::<CurrentEnvironment, u8>::from_str("0xf_fu8")?);
+        assert_eq!(-31, *Integer::<CurrentEnvironment, i8>::from_str("-0x1Fi8")?);
+        assert_eq!(i8::MIN, *Integer::<CurrentEnvironment, i8>::from_str("-0x80i8")?);
+        assert_eq!(u128::MAX, *Integer::<CurrentEnvironment, u128>::from_str(&format!("0x{:x}u128", u128::MAX))?);
+        assert_eq!(0xdead_beef, *Integer::<CurrentEnvironment, u32>::from_str("0xdead_beefu32")?);
+
+        // Ensure out-of-range values fail.
+        assert!(Integer::<CurrentEnvironment, u8>::parse("0x100u8").is_err());
+        assert!(Integer::<CurrentEnvironment, i8>::parse("0x80i8").is_err());
+        assert!(Integer::<CurrentEnvironment, i8>::parse("-0x81i8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::parse("-0x1u8").is_err());
+        // Ensure malformed literals fail.
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0xu8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0x_1u8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0xGu8").is_err());
+
+        // Ensure decimal parsing is unaffected.
+        assert_eq!(0, *Integer::<CurrentEnvironment, u8>::from_str("0u8")?);
+        assert_eq!(10, *Integer::<CurrentEnvironment, u8>::from_str("0_10u8")?);
+        assert_eq!(100, *Integer::<CurrentEnvironment, u8>::from_str("1_00u8")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_binary_and_octal() -> Result<()> {
+        /// Formats the given value as binary and octal literals, and checks that they parse back to the value.
+        fn check_parse_binary_and_octal<E: Environment, I: IntegerType>(rng: &mut TestRng) {
+            for _ in 0..ITERATIONS {
+                // Sample a random value.
+                let value: I = Uniform::rand(rng);
+
+                // Format the value with a sign and the magnitude in binary and octal.
+                let (sign, magnitude) = match I::is_signed() {
+                    true => {
+                        let value = value.to_i128().unwrap();
+                        (if value < 0 { "-" } else { "" }, value.unsigned_abs())
+                    }
+                    false => ("", value.to_u128().unwrap()),
+                };
+                let type_name = Integer::<E, I>::type_name();
+
+                for literal in [
+                    format!("{sign}0b{magnitude:b}{type_name}"),
+                    format!("{sign}0B{magnitude:b}{type_name}"),
+                    format!("{sign}0o{magnitude:o}{type_name}"),
+                    format!("{sign}0O{magnitude:o}{type_name}"),
+                ] {
+                    let (remainder, candidate) = Integer::<E, I>::parse(&literal).unwrap();
+                    assert_eq!(value, *candidate, "{literal}");
+                    assert_eq!("", remainder);
+                }
+            }
+        }
+
+        let mut rng = TestRng::default();
+
+        check_parse_binary_and_octal::<CurrentEnvironment, u8>(&mut rng);
+        check_parse_binary_and_octal::<CurrentEnvironment, u32>(&mut rng);
+        check_parse_binary_and_octal::<CurrentEnvironment, u128>(&mut rng);
+
+        check_parse_binary_and_octal::<CurrentEnvironment, i8>(&mut rng);
+        check_parse_binary_and_octal::<CurrentEnvironment, i64>(&mut rng);
+        check_parse_binary_and_octal::<CurrentEnvironment, i128>(&mut rng);
+
+        // Check underscores inside the digit groups.
+        assert_eq!(10, *Integer::<CurrentEnvironment, u8>::from_str("0b1010u8")?);
+        assert_eq!(255, *Integer::<CurrentEnvironment, u8>::from_str("0b1111_1111u8")?);
+        assert_eq!(15, *Integer::<CurrentEnvironment, u8>::from_str("0o17u8")?);
+        assert_eq!(0o1_777, *Integer::<CurrentEnvironment, u16>::from_str("0o1_777u16")?);
+        assert_eq!(-128, *Integer::<CurrentEnvironment, i8>::from_str("-0b1000_0000i8")?);
+        assert_eq!(-8, *Integer::<CurrentEnvironment, i16>::from_str("-0o1_0i16")?);
+
+        // Ensure out-of-range values fail.
+        assert!(Integer::<CurrentEnvironment, u8>::parse("0b1_0000_0000u8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::parse("0o400u8").is_err());
+        assert!(Integer::<CurrentEnvironment, i8>::parse("0b1000_0000i8").is_err());
+        assert!(Integer::<CurrentEnvironment, i8>::parse("-0o201i8").is_err());
+        // Ensure malformed literals fail.
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0bu8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0b2u8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0o8u8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0o_7u8").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a integer from the given element,